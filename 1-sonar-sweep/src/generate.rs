@@ -0,0 +1,26 @@
+//! Random sonar sweeps, shaped like the real puzzle input
+
+use rand::Rng;
+
+/// About as many depths as a real input
+pub const DEFAULT_SIZE: usize = 2000;
+
+/// `size` depths, wandering mostly downwards like the sea floor does
+pub fn depths<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<u64> {
+    let mut depth: u64 = rng.gen_range(100..200);
+
+    (0..size)
+        .map(|_| {
+            depth = depth.saturating_add_signed(rng.gen_range(-10..=20));
+            depth
+        })
+        .collect()
+}
+
+pub fn render(depths: &[u64]) -> String {
+    depths.iter().map(|d| format!("{}\n", d)).collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&depths(rng, size))
+}
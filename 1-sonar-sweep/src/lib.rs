@@ -0,0 +1,150 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{parse::parse_at, ParseError, Solution};
+use itertools::Itertools;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+pub mod profile;
+pub mod stream;
+
+pub fn count_increases(lines: &[u64]) -> usize {
+    lines.iter().tuple_windows().filter(|(a, b)| a < b).count()
+}
+
+pub fn transform_input(file: &str) -> Result<Vec<u64>, ParseError> {
+    file.lines()
+        .map(|line| parse_at(file, line, "a depth"))
+        .collect()
+}
+
+/// Fails for windows of no depths, which have no sums to compare
+pub(crate) fn check_window(n: usize) -> Result<()> {
+    if n == 0 {
+        bail!("Windows need at least one depth");
+    }
+
+    Ok(())
+}
+
+/// The sum of every window of `n` consecutive depths
+pub fn sum_windows(lines: &[u64], n: usize) -> Result<Vec<u64>> {
+    check_window(n)?;
+
+    lines
+        .windows(n)
+        .map(|window| {
+            window
+                .iter()
+                .try_fold(0u64, |sum, depth| sum.checked_add(*depth))
+                .ok_or_else(|| anyhow!("The window {:?} is too deep to add up", window))
+        })
+        .collect()
+}
+
+/// How many windows of `n` depths have a bigger sum than the window before
+///
+/// Neighbouring windows share all but their first and last depths, so this only compares `a[i]`
+/// with `a[i + n]` and never builds the sums
+pub fn count_window_increases(lines: &[u64], n: usize) -> Result<usize> {
+    check_window(n)?;
+
+    Ok(lines
+        .iter()
+        .zip(lines.iter().skip(n))
+        .filter(|(a, b)| a < b)
+        .count())
+}
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+    const NAME: &'static str = "sonar-sweep";
+
+    type Input = Vec<u64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        transform_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        count_window_increases(input, 1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        count_window_increases(input, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_part_a() {
+        let sample_input: Vec<u64> = transform_input(fixtures::SAMPLE).unwrap();
+        assert_eq!(count_increases(&sample_input), 7);
+    }
+
+    #[test]
+    fn test_sample_part_b() {
+        let sample_input: Vec<u64> = transform_input(fixtures::SAMPLE).unwrap();
+        assert_eq!(count_window_increases(&sample_input, 3).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_sum_three() {
+        let sample_input: Vec<u64> = transform_input(fixtures::SAMPLE).unwrap();
+        assert_eq!(
+            sum_windows(&sample_input, 3).unwrap(),
+            vec![607, 618, 618, 617, 647, 716, 769, 792]
+        );
+    }
+
+    #[test]
+    fn test_any_window() {
+        let sample_input: Vec<u64> = transform_input(fixtures::SAMPLE).unwrap();
+
+        for n in 1..=sample_input.len() + 1 {
+            let sums = sum_windows(&sample_input, n).unwrap();
+
+            assert_eq!(sums.len(), (sample_input.len() + 1).saturating_sub(n));
+            assert_eq!(
+                count_window_increases(&sample_input, n).unwrap(),
+                count_increases(&sums),
+                "Window of {}",
+                n
+            );
+        }
+
+        assert!(sum_windows(&sample_input, 0).is_err());
+        assert!(count_window_increases(&sample_input, 0).is_err());
+    }
+
+    #[test]
+    fn test_too_deep() {
+        assert!(sum_windows(&[1, u64::MAX - 1, 1], 3).is_err());
+        assert_eq!(
+            count_window_increases(&[1, u64::MAX - 1, 1, 2], 3).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_bad_depth() {
+        let err = transform_input("199\n200\n2O8\n210").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "2O8");
+    }
+}
@@ -0,0 +1,319 @@
+//! A report on the shape of the sea floor, beyond counting increases
+
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt,
+};
+
+use itertools::Itertools;
+use serde::Serialize;
+
+/// How to spot readings that don't fit in with their neighbours
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileOptions {
+    /// How many readings either side of a reading make up its local window
+    pub window: usize,
+    /// How many standard deviations from the local mean make a reading an anomaly
+    pub threshold: f64,
+}
+
+impl Default for ProfileOptions {
+    fn default() -> Self {
+        ProfileOptions {
+            window: 5,
+            threshold: 3.0,
+        }
+    }
+}
+
+/// Consecutive readings that all rise, fall or stay level
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Run {
+    /// The line of the first reading, counted from 1
+    pub line: usize,
+    /// How many readings the run covers
+    pub len: usize,
+    pub from: u64,
+    pub to: u64,
+}
+
+/// The change between two neighbouring readings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    /// The line of the later reading, counted from 1
+    pub line: usize,
+    pub from: u64,
+    pub to: u64,
+}
+
+/// A reading far outside its local window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Anomaly {
+    /// Counted from 1
+    pub line: usize,
+    pub depth: u64,
+    /// The mean of the readings around it, not counting itself
+    pub local_mean: f64,
+    /// How many standard deviations of the readings around it the reading is from their mean
+    pub deviations: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Profile {
+    pub readings: usize,
+    pub longest_increasing_run: Option<Run>,
+    pub longest_decreasing_run: Option<Run>,
+    pub biggest_rise: Option<Step>,
+    pub biggest_drop: Option<Step>,
+    /// Every run of at least two equal readings
+    pub plateaus: Vec<Run>,
+    /// How many times the depths switch between getting deeper and shallower, ignoring level steps
+    pub trend_reversals: usize,
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl Profile {
+    pub fn new(depths: &[u64], options: &ProfileOptions) -> Self {
+        let longest = |ordering| {
+            runs(depths, ordering)
+                .into_iter()
+                .min_by_key(|run| Reverse(run.len))
+        };
+
+        Profile {
+            readings: depths.len(),
+            longest_increasing_run: longest(Ordering::Less),
+            longest_decreasing_run: longest(Ordering::Greater),
+            biggest_rise: biggest_step(depths, |from, to| to.checked_sub(from)),
+            biggest_drop: biggest_step(depths, |from, to| from.checked_sub(to)),
+            plateaus: runs(depths, Ordering::Equal),
+            trend_reversals: trend_reversals(depths),
+            anomalies: anomalies(depths, options),
+        }
+    }
+}
+
+/// Every maximal run of at least two readings where each compares to the next with `ordering`
+fn runs(depths: &[u64], ordering: Ordering) -> Vec<Run> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+
+    for i in 1..=depths.len() {
+        let continues = i < depths.len() && depths[i - 1].cmp(&depths[i]) == ordering;
+
+        if !continues {
+            if i - start >= 2 {
+                runs.push(Run {
+                    line: start + 1,
+                    len: i - start,
+                    from: depths[start],
+                    to: depths[i - 1],
+                });
+            }
+            start = i;
+        }
+    }
+
+    runs
+}
+
+/// The first of the biggest steps by `size`, which is `None` for steps going the other way
+fn biggest_step(depths: &[u64], size: fn(u64, u64) -> Option<u64>) -> Option<Step> {
+    depths
+        .iter()
+        .tuple_windows()
+        .enumerate()
+        .filter_map(|(i, (&from, &to))| Some((size(from, to)?, i)))
+        .filter(|(size, _)| *size > 0)
+        .min_by_key(|&(size, i)| (Reverse(size), i))
+        .map(|(_, i)| Step {
+            line: i + 2,
+            from: depths[i],
+            to: depths[i + 1],
+        })
+}
+
+fn trend_reversals(depths: &[u64]) -> usize {
+    depths
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| a.cmp(b))
+        .filter(|ordering| ordering.is_ne())
+        .tuple_windows()
+        .filter(|(a, b)| a != b)
+        .count()
+}
+
+/// Readings more than `options.threshold` standard deviations from the mean of the readings around
+/// them
+///
+/// The standard deviation is floored at 1, since depths are whole numbers, so a single step off
+/// perfectly level neighbours doesn't count as an anomaly
+fn anomalies(depths: &[u64], options: &ProfileOptions) -> Vec<Anomaly> {
+    (0..depths.len())
+        .filter_map(|i| {
+            let before = &depths[i.saturating_sub(options.window)..i];
+            let after = &depths[i + 1..(i + 1 + options.window).min(depths.len())];
+            let neighbours = before.iter().chain(after).map(|&d| d as f64).collect_vec();
+
+            if neighbours.len() < 2 {
+                return None;
+            }
+
+            let count = neighbours.len() as f64;
+            let mean = neighbours.iter().sum::<f64>() / count;
+            let variance = neighbours.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count;
+            let deviations = (depths[i] as f64 - mean).abs() / variance.sqrt().max(1.0);
+
+            (deviations > options.threshold).then(|| Anomaly {
+                line: i + 1,
+                depth: depths[i],
+                local_mean: mean,
+                deviations,
+            })
+        })
+        .collect()
+}
+
+impl fmt::Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} readings from line {}, {} to {}",
+            self.len, self.line, self.from, self.to
+        )
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, {} to {}",
+            self.from.abs_diff(self.to),
+            self.line,
+            self.from,
+            self.to
+        )
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn or_none<T: fmt::Display>(value: &Option<T>) -> String {
+            value
+                .as_ref()
+                .map_or_else(|| "none".to_owned(), T::to_string)
+        }
+
+        writeln!(f, "Readings: {}", self.readings)?;
+        writeln!(
+            f,
+            "Longest increasing run: {}",
+            or_none(&self.longest_increasing_run)
+        )?;
+        writeln!(
+            f,
+            "Longest decreasing run: {}",
+            or_none(&self.longest_decreasing_run)
+        )?;
+        writeln!(f, "Biggest rise: {}", or_none(&self.biggest_rise))?;
+        writeln!(f, "Biggest drop: {}", or_none(&self.biggest_drop))?;
+        writeln!(f, "Plateaus: {}", self.plateaus.len())?;
+        for plateau in &self.plateaus {
+            writeln!(f, "  {}", plateau)?;
+        }
+        writeln!(f, "Trend reversals: {}", self.trend_reversals)?;
+        write!(f, "Anomalies: {}", self.anomalies.len())?;
+        for anomaly in &self.anomalies {
+            write!(
+                f,
+                "\n  line {}: {}, {:.1} standard deviations from the local mean of {:.1}",
+                anomaly.line, anomaly.depth, anomaly.deviations, anomaly.local_mean
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, transform_input};
+
+    #[test]
+    fn test_sample() {
+        let depths = transform_input(fixtures::SAMPLE).unwrap();
+        let profile = Profile::new(&depths, &ProfileOptions::default());
+
+        assert_eq!(
+            profile.longest_increasing_run,
+            Some(Run {
+                line: 1,
+                len: 4,
+                from: 199,
+                to: 210
+            })
+        );
+        assert_eq!(
+            profile.longest_decreasing_run,
+            Some(Run {
+                line: 4,
+                len: 2,
+                from: 210,
+                to: 200
+            })
+        );
+        assert_eq!(
+            profile.biggest_rise,
+            Some(Step {
+                line: 7,
+                from: 207,
+                to: 240
+            })
+        );
+        assert_eq!(
+            profile.biggest_drop,
+            Some(Step {
+                line: 5,
+                from: 210,
+                to: 200
+            })
+        );
+        assert_eq!(profile.plateaus, vec![]);
+        assert_eq!(profile.trend_reversals, 4);
+        assert_eq!(profile.anomalies, vec![]);
+    }
+
+    #[test]
+    fn test_plateaus_and_anomalies() {
+        let depths = [100, 101, 102, 500, 103, 104, 104, 104, 103];
+        let profile = Profile::new(&depths, &ProfileOptions::default());
+
+        assert_eq!(
+            profile.plateaus,
+            vec![Run {
+                line: 6,
+                len: 3,
+                from: 104,
+                to: 104
+            }]
+        );
+        assert_eq!(profile.trend_reversals, 3);
+        assert_eq!(
+            profile.anomalies.iter().map(|a| a.line).collect_vec(),
+            vec![4]
+        );
+    }
+
+    #[test]
+    fn test_too_few_readings() {
+        let profile = Profile::new(&[7], &ProfileOptions::default());
+
+        assert_eq!(profile.longest_increasing_run, None);
+        assert_eq!(profile.biggest_drop, None);
+        assert_eq!(profile.trend_reversals, 0);
+        assert!(profile.to_string().contains("Biggest rise: none"));
+    }
+}
@@ -0,0 +1,136 @@
+//! Counting increases as the readings arrive, for sonar logs too big to hold in memory
+
+use std::{collections::VecDeque, io::BufRead};
+
+use anyhow::{Context, Result};
+use aoc_common::parse::parse_at;
+
+use crate::check_window;
+
+/// Counts how many windows of `n` depths have a bigger sum than the window before, one reading at
+/// a time
+///
+/// Like [`crate::count_window_increases`] it only compares each reading with the one `n` before,
+/// so it never holds more than `n` readings, or more than it's been given for a huge `n`
+pub struct WindowIncreases {
+    n: usize,
+    recent: VecDeque<u64>,
+    increases: usize,
+}
+
+impl WindowIncreases {
+    pub fn new(n: usize) -> Result<Self> {
+        check_window(n)?;
+
+        Ok(WindowIncreases {
+            n,
+            recent: VecDeque::new(),
+            increases: 0,
+        })
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        if self.recent.len() == self.n {
+            if let Some(oldest) = self.recent.pop_front() {
+                if oldest < depth {
+                    self.increases += 1;
+                }
+            }
+        }
+
+        self.recent.push_back(depth);
+    }
+
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+}
+
+/// The depths in `reader`, one per line, failing on the first line that can't be read or isn't a
+/// depth
+pub fn depths<R: BufRead>(reader: R) -> impl Iterator<Item = Result<u64>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let line = line.with_context(|| format!("Couldn't read line {}", i + 1))?;
+
+        parse_at(&line, &line, "a depth").map_err(|e| e.offset_lines(i).into())
+    })
+}
+
+pub fn count_window_increases<I>(readings: I, n: usize) -> Result<usize>
+where
+    I: IntoIterator<Item = u64>,
+{
+    let mut counter = WindowIncreases::new(n)?;
+    readings.into_iter().for_each(|depth| counter.push(depth));
+
+    Ok(counter.increases())
+}
+
+/// Counts increases for each of the window sizes `ns` in a single pass over `reader`
+pub fn count_window_increases_in<R: BufRead>(reader: R, ns: &[usize]) -> Result<Vec<usize>> {
+    let mut counters = ns
+        .iter()
+        .map(|&n| WindowIncreases::new(n))
+        .collect::<Result<Vec<_>>>()?;
+
+    for depth in depths(reader) {
+        let depth = depth?;
+        counters.iter_mut().for_each(|counter| counter.push(depth));
+    }
+
+    Ok(counters.iter().map(WindowIncreases::increases).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::*;
+    use crate::{fixtures, transform_input};
+
+    #[test]
+    fn test_matches_slices() {
+        let sample = transform_input(fixtures::SAMPLE).unwrap();
+        let ns: Vec<usize> = (1..=sample.len() + 1).collect();
+
+        let streamed = count_window_increases_in(fixtures::SAMPLE.as_bytes(), &ns).unwrap();
+
+        for (n, increases) in ns.into_iter().zip(streamed) {
+            assert_eq!(
+                increases,
+                crate::count_window_increases(&sample, n).unwrap()
+            );
+            assert_eq!(
+                count_window_increases(sample.iter().copied(), n).unwrap(),
+                increases
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_window() {
+        assert!(WindowIncreases::new(0).is_err());
+        assert!(count_window_increases(0..10, 0).is_err());
+        assert!(count_window_increases_in("199\n200\n".as_bytes(), &[1, 0]).is_err());
+    }
+
+    #[test]
+    fn test_unbounded_readings() {
+        assert_eq!(count_window_increases(0..1_000_000, 3).unwrap(), 999_997);
+    }
+
+    #[test]
+    fn test_huge_window() {
+        assert_eq!(count_window_increases(0..10, usize::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let err =
+            count_window_increases_in("199\r\n200\r\n2O8\r\n210\r\n".as_bytes(), &[1]).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.line_text, "2O8");
+    }
+}
@@ -0,0 +1,63 @@
+//! Random navigation subsystems, shaped like the real puzzle input
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::close_char;
+
+/// About as many lines as a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+const OPENERS: [char; 4] = ['(', '[', '{', '<'];
+
+/// A line of about `len` characters that either leaves some chunks open or, when `corrupt`, closes
+/// one with the wrong character part way through
+pub fn line<R: Rng + ?Sized>(rng: &mut R, len: usize, corrupt: bool) -> String {
+    let corrupt_at = corrupt.then(|| rng.gen_range(0..len.max(1)));
+    let mut stack = vec![];
+    let mut line = String::with_capacity(len + 1);
+
+    for i in 0..len {
+        if Some(i) == corrupt_at {
+            let expected = stack.last().map(close_char);
+            let wrong = OPENERS
+                .map(|c| close_char(&c))
+                .into_iter()
+                .filter(|c| Some(*c) != expected)
+                .collect::<Vec<_>>();
+
+            line.push(*wrong.choose(rng).unwrap());
+        } else if stack.is_empty() || rng.gen_bool(0.55) {
+            let opener = *OPENERS.choose(rng).unwrap();
+            stack.push(opener);
+            line.push(opener);
+        } else {
+            line.push(close_char(&stack.pop().unwrap()));
+        }
+    }
+
+    if !corrupt && stack.is_empty() {
+        line.push(*OPENERS.choose(rng).unwrap());
+    }
+
+    line
+}
+
+/// `size` lines, about half of them corrupt, but always with an incomplete one first for part 2
+pub fn lines<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|i| {
+            let len = rng.gen_range(20..110);
+            let corrupt = i > 0 && rng.gen();
+
+            line(rng, len, corrupt)
+        })
+        .collect()
+}
+
+pub fn render(lines: &[String]) -> String {
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&lines(rng, size))
+}
@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::char_at, ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+fn close_char(c: &char) -> char {
+    match c {
+        '{' => '}',
+        '(' => ')',
+        '[' => ']',
+        '<' => '>',
+        _ => panic!("invalid char"),
+    }
+}
+fn score_invalid(c: &char) -> u64 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("invalid char"),
+    }
+}
+fn score_valid(c: &char) -> u64 {
+    match c {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => panic!("invalid char"),
+    }
+}
+
+/// Checks one `line` of `input`, which is the stack of chunks it left open or the first illegal
+/// closing character
+fn parse_line(input: &str, line: &str) -> Result<Result<Vec<char>, char>, ParseError> {
+    let mut stack = vec![];
+
+    for (i, character) in line.char_indices() {
+        match character {
+            '(' | '{' | '[' | '<' => stack.push(character),
+            ')' | '}' | ']' | '>' => {
+                if stack.last().map(close_char) == Some(character) {
+                    stack.pop();
+                } else {
+                    return Ok(Err(character));
+                }
+            }
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    char_at(line, i),
+                    format!("`{}` isn't a bracket", character),
+                ))
+            }
+        }
+    }
+
+    Ok(Ok(stack))
+}
+
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+    const NAME: &'static str = "syntax-scoring";
+
+    /// Each line is either the stack of chunks it left open, or the first illegal character
+    type Input = Vec<Result<Vec<char>, char>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| parse_line(input, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let corrupt = input
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+            .collect::<Vec<_>>();
+
+        Ok(corrupt.into_iter().map(score_invalid).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut scores = input
+            .iter()
+            .filter_map(|result| result.as_ref().ok())
+            .map(|stack| stack.iter().rev().map(close_char).collect::<Vec<_>>())
+            .map(|stack| {
+                let mut score: u64 = 0;
+
+                for c in stack.iter() {
+                    score = score
+                        .checked_mul(5)
+                        .and_then(|score| score.checked_add(score_valid(c)))
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Completing {} brackets scores too much for a u64",
+                                stack.len()
+                            )
+                        })?;
+                }
+
+                Ok(score)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        scores.sort_unstable();
+
+        scores
+            .get(scores.len() / 2)
+            .copied()
+            .ok_or_else(|| anyhow!("No incomplete lines"))
+    }
+}
@@ -0,0 +1,57 @@
+//! Random octopus grids, shaped like the real puzzle input
+
+use grid::Grid;
+use rand::Rng;
+
+use super::single_cycle;
+
+/// As wide and tall as a real input
+pub const DEFAULT_SIZE: usize = 10;
+
+/// How many random grids to try before settling for one that's sure to sync
+const ATTEMPTS: usize = 20;
+
+/// How long a random grid gets to sync, a few times what real inputs take
+const MAX_STEPS: usize = 1000;
+
+/// A `size` by `size` grid of energy levels whose octopuses all flash at once eventually, so
+/// part 2 has an answer
+///
+/// Random grids look most like real inputs, but more of them fall into a loop that never syncs as
+/// they grow. When none of a few do, every octopus starts on one of two neighbouring levels
+/// instead: the higher ones flash first and set off every octopus waiting a step behind them
+pub fn energies<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Grid<u8> {
+    for _ in 0..ATTEMPTS {
+        let mut grid = Grid::new(size, size, 0);
+        for energy in grid.values_mut() {
+            *energy = rng.gen_range(0..=9);
+        }
+
+        if syncs_within(&grid, MAX_STEPS) {
+            return grid;
+        }
+    }
+
+    let low = rng.gen_range(0..9);
+    let mut grid = Grid::new(size, size, low);
+    for energy in grid.values_mut() {
+        *energy += u8::from(rng.gen_bool(0.5));
+    }
+
+    grid
+}
+
+fn syncs_within(grid: &Grid<u8>, steps: usize) -> bool {
+    let mut grid = grid.clone();
+    let octopuses = grid.width() * grid.height();
+
+    (0..steps).any(|_| single_cycle(&mut grid) == octopuses)
+}
+
+pub fn render(energies: &Grid<u8>) -> String {
+    format!("{}\n", energies)
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&energies(rng, size.max(1)))
+}
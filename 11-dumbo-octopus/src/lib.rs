@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, Solution};
+use grid::Grid;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const SMALL: &str = include_str!("small.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+/// Steps every octopus once, returning how many of them flashed
+fn single_cycle(board: &mut Grid<u8>) -> usize {
+    let mut flashed = Grid::new(board.width(), board.height(), false);
+    let mut ready = vec![];
+
+    for pos in board.positions() {
+        board[pos] += 1;
+        if board[pos] > 9 {
+            ready.push(pos);
+        }
+    }
+
+    while let Some(pos) = ready.pop() {
+        if flashed[pos] {
+            continue;
+        }
+        flashed[pos] = true;
+
+        for n in board.neighbours8(pos) {
+            board[n] += 1;
+            if board[n] > 9 && !flashed[n] {
+                ready.push(n);
+            }
+        }
+    }
+
+    for value in board.values_mut() {
+        if *value > 9 {
+            *value = 0;
+        }
+    }
+
+    flashed.values().filter(|f| **f).count()
+}
+
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+    const NAME: &'static str = "dumbo-octopus";
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let mut board = input.clone();
+
+        Ok((0..100).map(|_| single_cycle(&mut board)).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut board = input.clone();
+        let octopuses = board.width() * board.height();
+
+        let mut seen = HashSet::new();
+
+        for i in 0.. {
+            let flashed = single_cycle(&mut board);
+
+            if flashed == octopuses {
+                return Ok(i + 1);
+            }
+
+            // Every step follows from the last, so once a state comes round again it's a loop
+            if !seen.insert(board.values().copied().collect::<Vec<_>>()) {
+                break;
+            }
+        }
+
+        Err(anyhow!(
+            "The octopuses fall into a loop without ever all flashing at once"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_cycle() {
+        let mut board = DumboOctopus::parse(fixtures::SMALL).unwrap();
+
+        assert_eq!(single_cycle(&mut board), 9);
+        assert_eq!(board.to_string(), "34543\n40004\n50005\n40004\n34543");
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(DumboOctopus::solve_part1(fixtures::SAMPLE).unwrap(), 1656);
+        assert_eq!(DumboOctopus::solve_part1(fixtures::MY).unwrap(), 1591);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(DumboOctopus::solve_part2(fixtures::SAMPLE).unwrap(), 195);
+        assert_eq!(DumboOctopus::solve_part2(fixtures::MY).unwrap(), 314);
+    }
+
+    #[test]
+    fn test_never_syncing() {
+        let err = DumboOctopus::solve_part2("00\n23").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "The octopuses fall into a loop without ever all flashing at once"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Random cave systems, shaped like the real puzzle input
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::{is_big, MAX_SMALL_CAVES};
+
+/// As many caves as a real input has besides `start` and `end`
+///
+/// The number of paths grows exponentially with the caves, so part 2 slows down fast above this
+pub const DEFAULT_SIZE: usize = 9;
+
+/// The `i`th cave name of its size, two letters and then as many more as it takes
+///
+/// Small caves never get past two letters, so they can't turn into `end` or `start`
+fn name(mut i: usize, big: bool) -> String {
+    let first = if big { b'A' } else { b'a' };
+    let mut letters = Vec::new();
+
+    for _ in 0..2 {
+        letters.push(first + (i % 26) as u8);
+        i /= 26;
+    }
+    while i > 0 {
+        i -= 1;
+        letters.push(first + (i % 26) as u8);
+        i /= 26;
+    }
+
+    letters.iter().rev().map(|&c| c as char).collect()
+}
+
+/// The connections between `start`, `end` and `size` other caves, about a third of them big
+///
+/// Every cave can be reached from `start`, and two big caves are never connected since that would
+/// allow infinitely many paths. Once there are as many small caves as the solver can track the
+/// rest are all big
+pub fn connections<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<(String, String)> {
+    let mut caves = vec!["start".to_owned()];
+    let (mut small, mut big) = (0, 0);
+    while caves.len() < size + 1 {
+        // Leaving room for `start` and `end`
+        if rng.gen_ratio(1, 3) || small + 2 >= MAX_SMALL_CAVES {
+            caves.push(name(big, true));
+            big += 1;
+        } else {
+            caves.push(name(small, false));
+            small += 1;
+        }
+    }
+    caves.push("end".to_owned());
+
+    let mut connections = Vec::new();
+    let mut seen = HashSet::new();
+    let mut connect = |a: &String, b: &String, connections: &mut Vec<_>| {
+        let allowed = a != b && !(is_big(a) && is_big(b));
+
+        if allowed && seen.insert((a.clone(), b.clone())) {
+            seen.insert((b.clone(), a.clone()));
+            connections.push((a.clone(), b.clone()));
+        }
+    };
+
+    // A spanning tree first, so nothing is cut off
+    for (i, cave) in caves.iter().enumerate().skip(1) {
+        let small = caves[..i].iter().filter(|c| !is_big(c)).collect::<Vec<_>>();
+        let earlier = if is_big(cave) {
+            *small.choose(rng).unwrap()
+        } else {
+            caves[..i].choose(rng).unwrap()
+        };
+
+        connect(earlier, cave, &mut connections);
+    }
+
+    for _ in 0..size * 3 / 2 {
+        let a = caves.choose(rng).unwrap();
+        let b = caves.choose(rng).unwrap();
+
+        connect(a, b, &mut connections);
+    }
+
+    connections.shuffle(rng);
+    connections
+}
+
+pub fn render(connections: &[(String, String)]) -> String {
+    connections
+        .iter()
+        .map(|(a, b)| format!("{}-{}\n", a, b))
+        .collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&connections(rng, size))
+}
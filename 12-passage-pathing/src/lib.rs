@@ -0,0 +1,296 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SMALL: &str = include_str!("small.input");
+    pub const MEDIUM: &str = include_str!("medium.input");
+    pub const LARGE: &str = include_str!("large.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+/// Every cave, mapped to the caves it connects to
+pub type Connections = HashMap<String, Vec<String>>;
+
+fn parse_connections(input: &str) -> Result<Connections, ParseError> {
+    let mut connections = Connections::new();
+
+    for line in input.lines() {
+        let (from, to) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input, line, "expected a connection like `start-A`"))?;
+
+        for cave in [from, to] {
+            if cave.is_empty() {
+                return Err(ParseError::at(input, cave, "expected a cave name"));
+            }
+        }
+        if is_big(from) && is_big(to) {
+            return Err(ParseError::at(
+                input,
+                line,
+                "two big caves can't be connected, there'd be infinitely many paths",
+            ));
+        }
+
+        connections
+            .entry(from.to_owned())
+            .or_default()
+            .push(to.to_owned());
+        connections
+            .entry(to.to_owned())
+            .or_default()
+            .push(from.to_owned());
+    }
+
+    Ok(connections)
+}
+
+fn make_paths<'input>(
+    connections: &'input Connections,
+    to: &'input str,
+    path: Vec<&'input str>,
+    path_filter: &dyn Fn(&Vec<&'input str>, &'input str) -> bool,
+) -> Vec<Vec<&'input str>> {
+    let mut paths = Vec::new();
+
+    let from = match path.last() {
+        Some(from) => *from,
+        None => return paths,
+    };
+
+    if from == to {
+        paths.push(path);
+    } else {
+        for next in connections
+            .get(from)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(|next| path_filter(&path, next))
+        {
+            let mut new_path = path.clone();
+            new_path.push(next);
+            paths.append(&mut make_paths(connections, to, new_path, path_filter));
+        }
+    }
+
+    paths
+}
+
+fn make_paths_part1<'input>(
+    connections: &'input Connections,
+    to: &'input str,
+    path: Vec<&'input str>,
+) -> Vec<Vec<&'input str>> {
+    make_paths(connections, to, path, &|path, next| {
+        !path.contains(&next) || is_big(next)
+    })
+}
+
+fn make_paths_part2<'input>(
+    connections: &'input Connections,
+    to: &'input str,
+    path: Vec<&'input str>,
+) -> Vec<Vec<&'input str>> {
+    make_paths(connections, to, path, &|path, next| {
+        !path.contains(&next)
+            || is_big(next)
+            || (next != "start"
+                && next != "end"
+                && path
+                    .iter()
+                    .filter(|&x| x.starts_with(|c: char| c.is_ascii_lowercase()))
+                    .counts()
+                    .iter()
+                    .all(|(_, count)| *count == 1))
+    })
+}
+
+/// Lists every path and counts them, slow but obviously right
+pub fn brute_force_part1(connections: &Connections) -> usize {
+    make_paths_part1(connections, "end", vec!["start"]).len()
+}
+
+pub fn brute_force_part2(connections: &Connections) -> usize {
+    make_paths_part2(connections, "end", vec!["start"]).len()
+}
+
+/// The most small caves, `start` and `end` included, that fit in the bits of the path counter
+pub const MAX_SMALL_CAVES: usize = u64::BITS as usize;
+
+pub(crate) fn is_big(cave: &str) -> bool {
+    cave.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Counts the paths from `start` to `end` without listing them, allowing one small cave to be
+/// visited twice when `revisit` is set
+///
+/// How many ways there are on from a cave only depends on which small caves are used up, so each
+/// of those is only counted once
+fn count_paths(connections: &Connections, revisit: bool) -> Result<usize> {
+    type Memo<'a> = HashMap<(&'a str, u64, bool), usize>;
+
+    /// The paths from `cave` to the end, or `None` if there are too many to count in a `usize`
+    fn count<'a>(
+        connections: &'a Connections,
+        bits: &HashMap<&str, u64>,
+        cave: &'a str,
+        visited: u64,
+        revisited: bool,
+        memo: &mut Memo<'a>,
+    ) -> Option<usize> {
+        if cave == "end" {
+            return Some(1);
+        }
+        if let Some(&paths) = memo.get(&(cave, visited, revisited)) {
+            return Some(paths);
+        }
+
+        let mut paths: usize = 0;
+        for next in connections.get(cave).into_iter().flatten() {
+            let next = next.as_str();
+
+            paths = paths.checked_add(match bits.get(next) {
+                None => count(connections, bits, next, visited, revisited, memo)?,
+                Some(bit) if visited & bit == 0 => {
+                    count(connections, bits, next, visited | bit, revisited, memo)?
+                }
+                Some(_) if !revisited && next != "start" => {
+                    count(connections, bits, next, visited, true, memo)?
+                }
+                Some(_) => 0,
+            })?;
+        }
+
+        memo.insert((cave, visited, revisited), paths);
+        Some(paths)
+    }
+
+    let small = connections.keys().filter(|c| !is_big(c)).collect_vec();
+    if small.len() > MAX_SMALL_CAVES {
+        bail!(
+            "There are {} small caves, only up to {} fit",
+            small.len(),
+            MAX_SMALL_CAVES
+        );
+    }
+    let bits = small
+        .into_iter()
+        .enumerate()
+        .map(|(i, cave)| (cave.as_str(), 1 << i))
+        .collect::<HashMap<_, _>>();
+
+    let start = bits.get("start").copied().unwrap_or(0);
+
+    count(
+        connections,
+        &bits,
+        "start",
+        start,
+        !revisit,
+        &mut HashMap::new(),
+    )
+    .ok_or_else(|| anyhow!("There are too many paths to count in a usize"))
+}
+
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+    const NAME: &'static str = "passage-pathing";
+
+    type Input = Connections;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_connections(input)
+    }
+
+    fn part1(connections: &Self::Input) -> Result<Self::Part1> {
+        count_paths(connections, false)
+    }
+
+    fn part2(connections: &Self::Input) -> Result<Self::Part2> {
+        count_paths(connections, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::property::{generated, same_answers};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test_generating_more_caves_than_fit() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let input = generate::input(&mut StdRng::seed_from_u64(1), 1400);
+        let connections = PassagePathing::parse(&input).unwrap();
+
+        assert_eq!(connections.len(), 1402);
+        assert_eq!(
+            connections.keys().filter(|cave| !is_big(cave)).count(),
+            MAX_SMALL_CAVES
+        );
+    }
+
+    #[test]
+    fn test_big_caves_connected() {
+        let err = PassagePathing::parse("start-A\nA-B\nB-end").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_fixtures() {
+        for (input, part1, part2) in [
+            (fixtures::SMALL, 10, 36),
+            (fixtures::MEDIUM, 19, 103),
+            (fixtures::LARGE, 226, 3509),
+        ] {
+            assert_eq!(PassagePathing::solve_part1(input).unwrap(), part1);
+            assert_eq!(PassagePathing::solve_part2(input).unwrap(), part2);
+        }
+    }
+
+    const CAVES: [&str; 7] = ["start", "end", "a", "b", "c", "A", "B"];
+
+    /// Small cave systems that always have a `start`, and never connect two big caves
+    fn cave_systems() -> impl Strategy<Value = String> {
+        vec((0..CAVES.len(), 0..CAVES.len()), 1..14)
+            .prop_map(|connections| {
+                connections
+                    .into_iter()
+                    .map(|(a, b)| (CAVES[a], CAVES[b]))
+                    .filter(|(a, b)| a != b && !(is_big(a) && is_big(b)))
+                    .map(|(a, b)| format!("{}-{}\n", a, b))
+                    .collect::<String>()
+            })
+            .prop_filter("needs a start", |input| input.contains("start"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_counting_matches_listing(input in generated(generate::input, 1..8)) {
+            same_answers::<PassagePathing, _>(&input, |c| Ok(brute_force_part1(c)), PassagePathing::part1)?;
+            same_answers::<PassagePathing, _>(&input, |c| Ok(brute_force_part2(c)), PassagePathing::part2)?;
+        }
+
+        #[test]
+        fn test_counting_matches_listing_anywhere(input in cave_systems()) {
+            same_answers::<PassagePathing, _>(&input, |c| Ok(brute_force_part1(c)), PassagePathing::part1)?;
+            same_answers::<PassagePathing, _>(&input, |c| Ok(brute_force_part2(c)), PassagePathing::part2)?;
+        }
+    }
+}
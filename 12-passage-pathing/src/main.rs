@@ -1,106 +1,13 @@
 use anyhow::Result;
-use itertools::Itertools;
-use std::collections::HashMap;
-
-fn parse_connections(input: &str) -> HashMap<&str, Vec<&str>> {
-    let mut connections = HashMap::new();
-
-    for line in input.lines() {
-        let mut words = line.split('-');
-        let from = words.next().unwrap();
-        let to = words.next().unwrap();
-
-        connections.entry(from).or_insert_with(Vec::new).push(to);
-        connections.entry(to).or_insert_with(Vec::new).push(from);
-    }
-
-    connections
-}
-
-fn make_paths<'input>(
-    connections: &HashMap<&'input str, Vec<&'input str>>,
-    to: &'input str,
-    path: Vec<&'input str>,
-    path_filter: &dyn Fn(&Vec<&'input str>, &'input str) -> bool,
-) -> Vec<Vec<&'input str>> {
-    let mut paths = Vec::new();
-
-    let from = *path.last().unwrap();
-
-    if from == to {
-        paths.push(path);
-    } else {
-        for next in connections
-            .get(from)
-            .unwrap()
-            .iter()
-            .filter(|next| path_filter(&path, next))
-        {
-            let mut new_path = path.clone();
-            new_path.push(next);
-            paths.append(&mut make_paths(connections, to, new_path, path_filter));
-        }
-    }
-
-    paths
-}
-
-fn make_paths_part1<'input>(
-    connections: &HashMap<&'input str, Vec<&'input str>>,
-    to: &'input str,
-    path: Vec<&'input str>,
-) -> Vec<Vec<&'input str>> {
-    make_paths(connections, to, path, &|path, next| {
-        !path.contains(&next) || next.chars().next().unwrap().is_ascii_uppercase()
-    })
-}
-
-fn make_paths_part2<'input>(
-    connections: &HashMap<&'input str, Vec<&'input str>>,
-    to: &'input str,
-    path: Vec<&'input str>,
-) -> Vec<Vec<&'input str>> {
-    make_paths(connections, to, path, &|path, next| {
-        !path.contains(&next)
-            || next.chars().next().unwrap().is_ascii_uppercase()
-            || (next != "start"
-                && next != "end"
-                && path
-                    .iter()
-                    .filter(|&x| x.chars().next().unwrap().is_ascii_lowercase())
-                    .counts()
-                    .iter()
-                    .all(|(_, count)| *count == 1))
-    })
-}
-
-fn part1_ans(s: &str) -> Result<usize> {
-    let connections = parse_connections(s);
-    let paths = make_paths_part1(&connections, "end", vec!["start"]);
-
-    Ok(paths.len())
-}
-
-fn part2_ans(s: &str) -> Result<usize> {
-    let connections = parse_connections(s);
-    let paths = make_paths_part2(&connections, "end", vec!["start"]);
-
-    Ok(paths.len())
-}
+use aoc_common::{input, solution, Solution};
+use passage_pathing::{PassagePathing, INPUTS_DIR};
 
 fn main() -> Result<()> {
-    println!("Part 1");
-    println!("Small: {}", part1_ans(include_str!("small.input"))?);
-    println!("Medium: {}", part1_ans(include_str!("medium.input"))?);
-    println!("Large: {}", part1_ans(include_str!("large.input"))?);
-    println!("My: {}", part1_ans(include_str!("my.input"))?);
-
-    println!();
-    println!("Part 2");
-    println!("Small: {}", part2_ans(include_str!("small.input"))?);
-    println!("Medium: {}", part2_ans(include_str!("medium.input"))?);
-    println!("Large: {}", part2_ans(include_str!("large.input"))?);
-    println!("My: {}", part2_ans(include_str!("my.input"))?);
+    let inputs = input::from_args(
+        PassagePathing::DAY,
+        INPUTS_DIR,
+        &["small", "medium", "large", "my"],
+    )?;
 
-    Ok(())
+    solution::print_answers::<PassagePathing>(&inputs)
 }
@@ -0,0 +1,83 @@
+//! Random sheets of transparent paper, shaped like the real puzzle input
+
+use std::collections::{BTreeSet, VecDeque};
+
+use rand::Rng;
+
+use super::{Fold, Input, Pos};
+
+/// About as many dots as a real input
+pub const DEFAULT_SIZE: usize = 800;
+
+/// How many folds a real input has
+pub const FOLDS: usize = 12;
+
+/// The size of the code left after folding, which is eight letters in the real input
+pub const CODE_WIDTH: u32 = 40;
+pub const CODE_HEIGHT: u32 = 6;
+
+/// Up to `size` dots on a sheet that folds down to a random [`CODE_WIDTH`] by [`CODE_HEIGHT`]
+/// pattern in [`FOLDS`] folds
+///
+/// The sheet is made by unfolding the pattern, so every fold is in the middle of the paper and no
+/// dot ever lands on a fold line
+pub fn sheet<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Input {
+    let mut dots = (0..size.max(1))
+        .map(|_| (rng.gen_range(0..CODE_WIDTH), rng.gen_range(0..CODE_HEIGHT)))
+        .collect::<BTreeSet<_>>();
+
+    let (mut width, mut height) = (CODE_WIDTH, CODE_HEIGHT);
+    let mut folds = VecDeque::with_capacity(FOLDS);
+
+    for _ in 0..FOLDS {
+        // Unfolding doubles a side, so alternate roughly evenly to keep both inside a u16
+        let along_x = if width / CODE_WIDTH == height / CODE_HEIGHT {
+            rng.gen()
+        } else {
+            width / CODE_WIDTH < height / CODE_HEIGHT
+        };
+
+        dots = dots
+            .into_iter()
+            .map(|(x, y)| match (along_x, rng.gen()) {
+                (true, true) => (2 * width - x, y),
+                (false, true) => (x, 2 * height - y),
+                _ => (x, y),
+            })
+            .collect();
+
+        if along_x {
+            folds.push_front(Fold::VerticalAxis(width as u16));
+            width = 2 * width + 1;
+        } else {
+            folds.push_front(Fold::HorizontalAxis(height as u16));
+            height = 2 * height + 1;
+        }
+    }
+
+    Input {
+        holes: dots.into_iter().map(|(x, y)| Pos { x, y }).collect(),
+        folds,
+    }
+}
+
+pub fn render(sheet: &Input) -> String {
+    let mut s = String::new();
+
+    for hole in &sheet.holes {
+        s.push_str(&format!("{},{}\n", hole.x, hole.y));
+    }
+    s.push('\n');
+    for fold in &sheet.folds {
+        match fold {
+            Fold::HorizontalAxis(y) => s.push_str(&format!("fold along y={}\n", y)),
+            Fold::VerticalAxis(x) => s.push_str(&format!("fold along x={}\n", x)),
+        }
+    }
+
+    s
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&sheet(rng, size))
+}
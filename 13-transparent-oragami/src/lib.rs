@@ -0,0 +1,210 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::parse_at, ParseError, Solution};
+use itertools::Itertools;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct Pos {
+    x: u32,
+    y: u32,
+}
+
+impl Pos {
+    /// Parses one `line` of `input`, ie `6,10`
+    fn parse(input: &str, line: &str) -> Result<Pos, ParseError> {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(input, line, "expected a hole like `6,10`"))?;
+
+        Ok(Pos {
+            x: parse_at(input, x, "a coordinate")?,
+            y: parse_at(input, y, "a coordinate")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Fold {
+    HorizontalAxis(u16),
+    VerticalAxis(u16),
+}
+
+impl Fold {
+    /// Parses one `line` of `input`, ie `fold along y=7`
+    fn parse(input: &str, line: &str) -> Result<Fold, ParseError> {
+        let (axis, pos) = line
+            .strip_prefix("fold along ")
+            .and_then(|fold| fold.split_once('='))
+            .ok_or_else(|| ParseError::at(input, line, "expected a fold like `fold along y=7`"))?;
+        let pos = parse_at(input, pos, "a fold line")?;
+
+        match axis {
+            "y" => Ok(Fold::HorizontalAxis(pos)),
+            "x" => Ok(Fold::VerticalAxis(pos)),
+            _ => Err(ParseError::at(
+                input,
+                axis,
+                format!("`{}` isn't an axis, expected x or y", axis),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    holes: Vec<Pos>,
+    folds: VecDeque<Fold>,
+}
+
+impl Input {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let (holes, folds) = s.split_once("\n\n").ok_or_else(|| {
+            ParseError::end_of_input(s, "expected a blank line and then the folds")
+        })?;
+
+        let holes = holes
+            .lines()
+            .map(|line| Pos::parse(s, line))
+            .collect::<Result<_, _>>()?;
+        let folds = folds
+            .lines()
+            .map(|line| Fold::parse(s, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { holes, folds })
+    }
+
+    fn fold_first(&mut self) -> Result<()> {
+        if let Some(fold) = self.folds.pop_front() {
+            match fold {
+                Fold::HorizontalAxis(pos) => {
+                    self.fold_horizontal(pos)?;
+                }
+                Fold::VerticalAxis(pos) => {
+                    self.fold_vertical(pos)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fold_horizontal(&mut self, pos: u16) -> Result<()> {
+        for hole in self.holes.iter_mut() {
+            hole.y = fold_onto(hole.y, pos.into()).ok_or_else(|| {
+                anyhow!("Hole {},{} can't be folded along y={}", hole.x, hole.y, pos)
+            })?;
+        }
+
+        self.holes.sort();
+        self.holes.dedup();
+
+        Ok(())
+    }
+
+    fn fold_vertical(&mut self, pos: u16) -> Result<()> {
+        for hole in self.holes.iter_mut() {
+            hole.x = fold_onto(hole.x, pos.into()).ok_or_else(|| {
+                anyhow!("Hole {},{} can't be folded along x={}", hole.x, hole.y, pos)
+            })?;
+        }
+
+        self.holes.sort();
+        self.holes.dedup();
+
+        Ok(())
+    }
+}
+
+/// Where `coord` ends up after folding along `line`, unless it's on the line itself or far
+/// enough past it to fold off the other edge of the paper
+fn fold_onto(coord: u32, line: u32) -> Option<u32> {
+    match coord.cmp(&line) {
+        Ordering::Less => Some(coord),
+        Ordering::Equal => None,
+        Ordering::Greater => line.checked_sub(coord - line),
+    }
+}
+
+/// The biggest folded sheet that gets drawn, the real code is 40x6
+const MAX_CODE_SIZE: u32 = 1000;
+
+pub struct TransparentOragami;
+
+impl Solution for TransparentOragami {
+    const DAY: u8 = 13;
+    const NAME: &'static str = "transparent-oragami";
+
+    type Input = Input;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let mut input = input.clone();
+
+        input.fold_first()?;
+
+        Ok(input.holes.len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut input = input.clone();
+
+        while !input.folds.is_empty() {
+            input.fold_first()?;
+        }
+
+        let max_x = input
+            .holes
+            .iter()
+            .map(|p| p.x)
+            .max()
+            .ok_or_else(|| anyhow!("No holes left"))?;
+        let max_y = input
+            .holes
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .ok_or_else(|| anyhow!("No holes left"))?;
+
+        if max_x >= MAX_CODE_SIZE || max_y >= MAX_CODE_SIZE {
+            return Err(anyhow!(
+                "The folded paper is still {}x{}, too big to read a code from",
+                max_x + 1,
+                max_y + 1
+            ));
+        }
+
+        let mut lines_grouped: HashMap<u32, Vec<_>> = HashMap::new();
+        for h in input.holes.into_iter() {
+            lines_grouped.entry(h.y).or_default().push(h.x);
+        }
+
+        Ok((0..=max_y)
+            .map(|y| {
+                let v = lines_grouped.entry(y).or_default();
+                (0..=max_x + 1)
+                    .map(|x| if v.contains(&x) { '█' } else { ' ' })
+                    .collect::<String>()
+            })
+            .join("\n"))
+    }
+}
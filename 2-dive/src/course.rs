@@ -0,0 +1,323 @@
+//! Course files, which grow the puzzle's list of moves into a small language
+//!
+//! ```text
+//! # Comments run to the end of the line, and blank lines are skipped
+//! forward 5
+//! back 2
+//! waypoint reef
+//! repeat 3 {
+//!     down 1
+//!     forward 2
+//! }
+//! ```
+//!
+//! Every puzzle input is a course file that only uses `forward`, `up` and `down`
+
+use std::collections::HashSet;
+
+use aoc_common::{parse::parse_at, ParseError};
+
+use super::{Move, MoveError};
+
+/// The most moves a course can expand to, so a few nested `repeat`s can't ask for more memory
+/// than there is
+pub const MAX_MOVES: usize = 10_000_000;
+
+/// A name for the point in a course it appears at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Waypoint {
+    pub name: String,
+    /// How many moves come before it, which is also its index in a trajectory's states
+    pub moves_before: usize,
+    /// Counted from 1
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Course {
+    /// Every move in order, with `repeat` blocks written out in full
+    pub moves: Vec<Move>,
+    /// The line, counted from 1, each of `moves` came from
+    pub lines: Vec<usize>,
+    pub waypoints: Vec<Waypoint>,
+}
+
+/// A `repeat` whose closing `}` hasn't been reached yet
+struct Block<'a> {
+    header: &'a str,
+    count: usize,
+    /// How many moves came before the block
+    start: usize,
+}
+
+impl Course {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut course = Course::default();
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut names = HashSet::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let code = line.split('#').next().unwrap_or_default().trim();
+            let mut words = code.split_whitespace();
+
+            match words.next() {
+                None => continue,
+                Some("repeat") => {
+                    let count = words.next().ok_or_else(|| {
+                        ParseError::after(input, code, "expected how many times to repeat")
+                    })?;
+                    let count = parse_at(input, count, "a repeat count")?;
+
+                    match words.next() {
+                        Some("{") => {}
+                        Some(other) => {
+                            return Err(ParseError::at(
+                                input,
+                                other,
+                                "expected `{` to open the block",
+                            ))
+                        }
+                        None => {
+                            return Err(ParseError::after(
+                                input,
+                                code,
+                                "expected `{` to open the block",
+                            ))
+                        }
+                    }
+
+                    blocks.push(Block {
+                        header: code,
+                        count,
+                        start: course.moves.len(),
+                    });
+                }
+                Some("}") => {
+                    let block = blocks.pop().ok_or_else(|| {
+                        ParseError::at(input, code, "there's no `repeat` for this `}` to close")
+                    })?;
+
+                    course.repeat(block.start, block.count).ok_or_else(|| {
+                        ParseError::at(
+                            input,
+                            code,
+                            format!("this block repeats to more than {} moves", MAX_MOVES),
+                        )
+                    })?;
+                }
+                Some("waypoint") => {
+                    let name = words.next().ok_or_else(|| {
+                        ParseError::after(input, code, "expected a name for the waypoint")
+                    })?;
+
+                    if !blocks.is_empty() {
+                        return Err(ParseError::at(
+                            input,
+                            code,
+                            "waypoints can't be inside a `repeat`, they'd be passed more than once",
+                        ));
+                    }
+                    if !names.insert(name) {
+                        return Err(ParseError::at(
+                            input,
+                            name,
+                            format!("there's already a waypoint called `{}`", name),
+                        ));
+                    }
+
+                    course.waypoints.push(Waypoint {
+                        name: name.to_owned(),
+                        moves_before: course.moves.len(),
+                        line: i + 1,
+                    });
+                }
+                Some(_) => {
+                    course.moves.push(Move::parse(input, code)?);
+                    course.lines.push(i + 1);
+                    continue;
+                }
+            }
+
+            if let Some(extra) = words.next() {
+                return Err(ParseError::at(
+                    input,
+                    extra,
+                    format!("unexpected `{}`", extra),
+                ));
+            }
+        }
+
+        match blocks.pop() {
+            Some(block) => Err(ParseError::at(
+                input,
+                block.header,
+                "this `repeat` is never closed with `}`",
+            )),
+            None => Ok(course),
+        }
+    }
+
+    /// Writes out the moves from `start` on `count` times in all, or returns `None` if that would
+    /// make more than [`MAX_MOVES`]
+    fn repeat(&mut self, start: usize, count: usize) -> Option<()> {
+        let body = start..self.moves.len();
+        let total = start.checked_add(body.len().checked_mul(count)?)?;
+
+        if total > MAX_MOVES {
+            return None;
+        }
+
+        if count == 0 {
+            self.moves.truncate(start);
+            self.lines.truncate(start);
+        }
+        for _ in 1..count {
+            self.moves.extend_from_within(body.clone());
+            self.lines.extend_from_within(body.clone());
+        }
+
+        Some(())
+    }
+
+    pub fn waypoint(&self, name: &str) -> Option<&Waypoint> {
+        self.waypoints.iter().find(|waypoint| waypoint.name == name)
+    }
+
+    /// Adds the line `error`'s move came from
+    pub fn locate(&self, error: MoveError) -> anyhow::Error {
+        let line = self.lines[error.index];
+
+        anyhow::Error::new(error).context(format!("Line {} of the course", line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, model::Aimed, Direction, Sub};
+
+    fn moves(course: &str) -> Vec<String> {
+        Course::parse(course)
+            .unwrap()
+            .moves
+            .iter()
+            .map(Move::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_puzzle_inputs() {
+        for input in [fixtures::SAMPLE, fixtures::MY] {
+            let course = Course::parse(input).unwrap();
+
+            assert_eq!(course.moves.len(), input.lines().count());
+            assert_eq!(
+                course.lines,
+                (1..=input.lines().count()).collect::<Vec<_>>()
+            );
+            assert!(course.waypoints.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_comments_and_back() {
+        let course = "# Out to the reef\n\nforward 5 # full ahead\n  back 2\n\n";
+
+        assert_eq!(moves(course), ["forward 5", "back 2"]);
+        assert_eq!(Course::parse(course).unwrap().lines, [3, 4]);
+        assert_eq!(
+            Course::parse(course).unwrap().moves[1].direction,
+            Direction::Back
+        );
+    }
+
+    #[test]
+    fn test_repeat() {
+        let course = "down 1\nrepeat 2 {\n  forward 1\n  repeat 3 {\n    up 1\n  }\n}\nrepeat 0 {\n  down 9\n}";
+
+        assert_eq!(
+            moves(course),
+            [
+                "down 1",
+                "forward 1",
+                "up 1",
+                "up 1",
+                "up 1",
+                "forward 1",
+                "up 1",
+                "up 1",
+                "up 1"
+            ]
+        );
+        assert_eq!(
+            Course::parse(course).unwrap().lines,
+            [1, 3, 5, 5, 5, 3, 5, 5, 5]
+        );
+    }
+
+    #[test]
+    fn test_waypoints() {
+        let course = Course::parse("down 5\nforward 2\nwaypoint reef\nforward 1").unwrap();
+        let reef = course.waypoint("reef").unwrap();
+        let trajectory = Sub::record(&Aimed, &course.moves).unwrap();
+
+        assert_eq!((reef.moves_before, reef.line), (2, 3));
+        assert_eq!(trajectory.states()[reef.moves_before].pos, (2, 10));
+        assert_eq!(course.waypoint("wreck"), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        for (course, location, message) in [
+            ("forward 5 6", (1, 11), "unexpected `6` after the distance"),
+            (
+                "repeat 2 {\nforward 1",
+                (1, 1),
+                "this `repeat` is never closed with `}`",
+            ),
+            (
+                "forward 1\n}",
+                (2, 1),
+                "there's no `repeat` for this `}` to close",
+            ),
+            (
+                "repeat x {\n}",
+                (1, 8),
+                "couldn't parse `x` as a repeat count: invalid digit found in string",
+            ),
+            ("repeat 2\n}", (1, 9), "expected `{` to open the block"),
+            (
+                "repeat 2 {\nwaypoint a\n}",
+                (2, 1),
+                "waypoints can't be inside a `repeat`, they'd be passed more than once",
+            ),
+            (
+                "waypoint a\nwaypoint a",
+                (2, 10),
+                "there's already a waypoint called `a`",
+            ),
+            ("waypoint a b", (1, 12), "unexpected `b`"),
+            (
+                "repeat 10000 {\nrepeat 10000 {\nforward 1\n}\n}",
+                (5, 1),
+                "this block repeats to more than 10000000 moves",
+            ),
+        ] {
+            let err = Course::parse(course).unwrap_err();
+
+            assert_eq!((err.line, err.column), location, "{}", err);
+            assert_eq!(err.message, message);
+        }
+    }
+
+    #[test]
+    fn test_move_errors_name_the_line() {
+        let course = Course::parse("forward 1\nrepeat 2 {\n  up 1\n}").unwrap();
+        let err = crate::eval_simple_moves(&course.moves).unwrap_err();
+
+        assert_eq!(
+            format!("{:#}", course.locate(err)),
+            "Line 3 of the course: Move 2 takes the sub above the surface"
+        );
+    }
+}
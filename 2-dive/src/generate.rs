@@ -0,0 +1,79 @@
+//! Random planned courses, shaped like the real puzzle input
+
+use rand::Rng;
+
+use super::{
+    model::{Aimed, MovementModel, Simple},
+    Direction, Move, Sub,
+};
+
+/// About as many moves as a real input
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// Where both ways of reading the course have taken the sub so far
+#[derive(Default, Clone)]
+struct Subs {
+    simple: Sub,
+    aimed: Sub,
+}
+
+impl Subs {
+    /// Takes `m` if neither reading surfaces the sub or overflows its answer
+    fn take(&self, m: &Move) -> Option<Subs> {
+        let mut next = self.clone();
+        Simple.apply(&mut next.simple, m).ok()?;
+        Aimed.apply(&mut next.aimed, m).ok()?;
+
+        for sub in [&next.simple, &next.aimed] {
+            sub.pos.0.checked_mul(sub.pos.1)?;
+        }
+
+        Some(next)
+    }
+}
+
+/// Up to `size` moves that keep the sub underwater under either reading of the course, stopping
+/// early if every move would overflow an answer
+pub fn moves<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<Move> {
+    let mut subs = Subs::default();
+    let mut moves = Vec::with_capacity(size);
+
+    while moves.len() < size {
+        let direction = match rng.gen_range(0..6) {
+            0..=2 => Direction::Forward,
+            3 | 4 => Direction::Down,
+            _ => Direction::Up,
+        };
+        let wanted = Move {
+            direction,
+            distance: rng.gen_range(1..=9),
+        };
+
+        let fallbacks =
+            [Direction::Down, Direction::Up, Direction::Forward].map(|direction| Move {
+                direction,
+                distance: 1,
+            });
+        let next = std::iter::once(wanted)
+            .chain(fallbacks)
+            .find_map(|m| subs.take(&m).map(|next| (m, next)));
+
+        match next {
+            Some((m, next)) => {
+                moves.push(m);
+                subs = next;
+            }
+            None => break,
+        }
+    }
+
+    moves
+}
+
+pub fn render(moves: &[Move]) -> String {
+    moves.iter().map(|m| format!("{}\n", m)).collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&moves(rng, size))
+}
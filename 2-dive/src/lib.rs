@@ -0,0 +1,344 @@
+use std::{error::Error, fmt};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::parse_at, ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod course;
+pub mod generate;
+pub mod model;
+pub mod trajectory;
+
+use course::Course;
+use model::{Aimed, MovementModel, Simple};
+use trajectory::Trajectory;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Sub {
+    pub pos: Pos,
+    pub aim: i64,
+}
+
+impl Sub {
+    /// Runs `moves` from the surface, reading them with `model`
+    pub fn run<M>(model: &M, moves: &[Move]) -> Result<Self, MoveError>
+    where
+        M: MovementModel + ?Sized,
+    {
+        Sub::run_with(model, moves, |_| {})
+    }
+
+    /// Like [`Sub::run`], but keeps where the sub was after every move
+    pub fn record<M>(model: &M, moves: &[Move]) -> Result<Trajectory, MoveError>
+    where
+        M: MovementModel + ?Sized,
+    {
+        let mut states = vec![Sub::default()];
+        Sub::run_with(model, moves, |sub| states.push(sub.clone()))?;
+
+        Ok(Trajectory::new(states))
+    }
+
+    fn run_with<M>(
+        model: &M,
+        moves: &[Move],
+        mut after_each: impl FnMut(&Sub),
+    ) -> Result<Self, MoveError>
+    where
+        M: MovementModel + ?Sized,
+    {
+        let mut sub = Sub::default();
+
+        for (index, m) in moves.iter().enumerate() {
+            model
+                .apply(&mut sub, m)
+                .map_err(|kind| MoveError { index, kind })?;
+            after_each(&sub);
+        }
+
+        Ok(sub)
+    }
+
+    pub fn from_moves(moves: &[Move]) -> Result<Self, MoveError> {
+        Sub::run(&Aimed, moves)
+    }
+}
+
+/// Horizontal position and depth, where depth grows downwards from 0 at the surface
+pub type Pos = (i64, i64);
+
+/// Why a move couldn't be made
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveErrorKind {
+    /// It would take the sub above the surface
+    Surfaced,
+    /// It would take the position or aim past what fits in an `i64`
+    Overflow,
+}
+
+/// A move in a course that couldn't be made
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveError {
+    /// Which move, counted from 0
+    pub index: usize,
+    pub kind: MoveErrorKind,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problem = match self.kind {
+            MoveErrorKind::Surfaced => "takes the sub above the surface",
+            MoveErrorKind::Overflow => "takes the sub further than fits in an i64",
+        };
+
+        write!(f, "Move {} {}", self.index + 1, problem)
+    }
+}
+
+impl Error for MoveError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    /// Reverses along the same line moving forward would take
+    Back,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn from_str(s: &str) -> Option<Direction> {
+        match s {
+            "forward" => Some(Direction::Forward),
+            "back" => Some(Direction::Back),
+            "up" => Some(Direction::Up),
+            "down" => Some(Direction::Down),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Direction::Forward => "forward",
+            Direction::Back => "back",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Move {
+    pub direction: Direction,
+    pub distance: u32,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.distance)
+    }
+}
+
+impl Move {
+    /// Parses one `line` of `input`, ie `forward 5`
+    fn parse(input: &str, line: &str) -> Result<Move, ParseError> {
+        let mut parts = line.split_whitespace();
+
+        let word = parts
+            .next()
+            .ok_or_else(|| ParseError::at(input, line, "expected a move, found a blank line"))?;
+        let direction = Direction::from_str(word).ok_or_else(|| {
+            ParseError::at(
+                input,
+                word,
+                format!(
+                    "unknown direction `{}`, expected forward, back, up or down",
+                    word
+                ),
+            )
+        })?;
+
+        let distance = parts.next().ok_or_else(|| {
+            ParseError::after(input, word, "expected a distance after the direction")
+        })?;
+        let distance = parse_at(input, distance, "a distance")?;
+
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::at(
+                input,
+                extra,
+                format!("unexpected `{}` after the distance", extra),
+            ));
+        }
+
+        Ok(Move {
+            direction,
+            distance,
+        })
+    }
+}
+
+/// Just the moves of a course file, see [`Course`] for the format
+pub fn parse_file(s: &str) -> Result<Vec<Move>, ParseError> {
+    Course::parse(s).map(|course| course.moves)
+}
+
+pub fn eval_simple_moves(moves: &[Move]) -> Result<Pos, MoveError> {
+    Sub::run(&Simple, moves).map(|sub| sub.pos)
+}
+
+fn product(x: i64, y: i64) -> Result<i64> {
+    x.checked_mul(y)
+        .ok_or_else(|| anyhow!("The product of {} and {} doesn't fit in an i64", x, y))
+}
+
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+    const NAME: &'static str = "dive";
+
+    type Input = Course;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Course::parse(input)
+    }
+
+    fn part1(course: &Self::Input) -> Result<Self::Part1> {
+        let (x, y) = eval_simple_moves(&course.moves).map_err(|e| course.locate(e))?;
+
+        product(x, y)
+    }
+
+    fn part2(course: &Self::Input) -> Result<Self::Part2> {
+        let Sub { pos: (x, y), .. } =
+            Sub::from_moves(&course.moves).map_err(|e| course.locate(e))?;
+
+        product(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Direction::from_str("forward"), Some(Direction::Forward));
+        assert_eq!(Direction::from_str("up"), Some(Direction::Up));
+        assert_eq!(Direction::from_str("down"), Some(Direction::Down));
+
+        assert_eq!(
+            Move::parse("forward 10", "forward 10"),
+            Ok(Move {
+                direction: Direction::Forward,
+                distance: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let str = "forward 10\nup 1\ndown 4";
+
+        assert_eq!(
+            parse_file(str).unwrap(),
+            vec![
+                Move {
+                    direction: Direction::Forward,
+                    distance: 10,
+                },
+                Move {
+                    direction: Direction::Up,
+                    distance: 1,
+                },
+                Move {
+                    direction: Direction::Down,
+                    distance: 4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_file("forward 10\nsideways 1").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.message,
+            "unknown direction `sideways`, expected forward, back, up or down"
+        );
+
+        let err = parse_file("forward 10\ndown").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = parse_file("up -1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 4, "-1"));
+    }
+
+    #[test]
+    fn test_surfacing() {
+        let moves = parse_file("forward 1\ndown 1\nup 2").unwrap();
+        assert_eq!(
+            eval_simple_moves(&moves),
+            Err(MoveError {
+                index: 2,
+                kind: MoveErrorKind::Surfaced
+            })
+        );
+
+        let moves = parse_file("forward 1\nup 1\nforward 1").unwrap();
+        assert_eq!(
+            Sub::from_moves(&moves),
+            Err(MoveError {
+                index: 2,
+                kind: MoveErrorKind::Surfaced
+            })
+        );
+
+        let err = Dive::solve_part2("forward 1\nup 1\n\nforward 1").unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Line 4 of the course: Move 3 takes the sub above the surface"
+        );
+        assert_eq!(err.downcast_ref::<MoveError>().map(|e| e.index), Some(2));
+    }
+
+    #[test]
+    fn test_aiming_up_underwater() {
+        let moves = parse_file("down 2\nforward 3\nup 3\nforward 2").unwrap();
+
+        assert_eq!(
+            Sub::from_moves(&moves),
+            Ok(Sub {
+                pos: (5, 4),
+                aim: -1
+            })
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let moves = parse_file("down 4294967295\nforward 4294967295").unwrap();
+
+        assert_eq!(
+            Sub::from_moves(&moves),
+            Err(MoveError {
+                index: 1,
+                kind: MoveErrorKind::Overflow
+            })
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Ways of reading a course, so the same moves can steer the sub differently
+
+use super::{Direction, Move, MoveErrorKind, Sub};
+
+/// How a move changes the sub
+///
+/// Implement this for new behaviour, like drift or currents, and run it with [`Sub::run`]
+pub trait MovementModel {
+    /// Applies `m` to `sub`, leaving `sub` where it was if the move can't be made
+    fn apply(&self, sub: &mut Sub, m: &Move) -> Result<(), MoveErrorKind>;
+}
+
+/// Part 1's reading, where up and down change the depth directly and the aim is never used
+pub struct Simple;
+
+impl MovementModel for Simple {
+    fn apply(&self, sub: &mut Sub, m: &Move) -> Result<(), MoveErrorKind> {
+        let (x, y) = sub.pos;
+        let distance = i64::from(m.distance);
+
+        sub.pos = match m.direction {
+            Direction::Forward => (checked(x.checked_add(distance))?, y),
+            Direction::Back => (checked(x.checked_sub(distance))?, y),
+            Direction::Up => (x, underwater(checked(y.checked_sub(distance))?)?),
+            Direction::Down => (x, checked(y.checked_add(distance))?),
+        };
+
+        Ok(())
+    }
+}
+
+/// Part 2's reading, where up and down turn the sub and moving forward dives along its aim, with
+/// moving back retracing that line
+pub struct Aimed;
+
+impl MovementModel for Aimed {
+    fn apply(&self, sub: &mut Sub, m: &Move) -> Result<(), MoveErrorKind> {
+        let distance = i64::from(m.distance);
+
+        match m.direction {
+            Direction::Up => sub.aim = checked(sub.aim.checked_sub(distance))?,
+            Direction::Down => sub.aim = checked(sub.aim.checked_add(distance))?,
+            Direction::Forward | Direction::Back => {
+                let distance = if m.direction == Direction::Back {
+                    -distance
+                } else {
+                    distance
+                };
+                let x = checked(sub.pos.0.checked_add(distance))?;
+                let y = checked(
+                    sub.aim
+                        .checked_mul(distance)
+                        .and_then(|dive| sub.pos.1.checked_add(dive)),
+                )?;
+
+                sub.pos = (x, underwater(y)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Turns the `None` from a `checked_` operation into an overflow
+pub fn checked(value: Option<i64>) -> Result<i64, MoveErrorKind> {
+    value.ok_or(MoveErrorKind::Overflow)
+}
+
+/// Fails when `depth` is above the surface
+pub fn underwater(depth: i64) -> Result<i64, MoveErrorKind> {
+    if depth < 0 {
+        Err(MoveErrorKind::Surfaced)
+    } else {
+        Ok(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, parse_file, Pos};
+
+    /// A current that carries the sub one further forward for every move it makes
+    struct Current;
+
+    impl MovementModel for Current {
+        fn apply(&self, sub: &mut Sub, m: &Move) -> Result<(), MoveErrorKind> {
+            Simple.apply(sub, m)?;
+            sub.pos.0 = checked(sub.pos.0.checked_add(1))?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_models() {
+        let moves = parse_file(fixtures::SAMPLE).unwrap();
+        let position = |model: &dyn MovementModel| -> Pos { Sub::run(model, &moves).unwrap().pos };
+
+        assert_eq!(position(&Simple), (15, 10));
+        assert_eq!(position(&Aimed), (15, 60));
+        assert_eq!(position(&Current), (21, 10));
+    }
+
+    #[test]
+    fn test_back() {
+        let moves = parse_file("down 2\nforward 5\nup 1\nback 3").unwrap();
+
+        assert_eq!(Sub::run(&Simple, &moves).unwrap().pos, (2, 1));
+        assert_eq!(Sub::run(&Aimed, &moves).unwrap().pos, (2, 7));
+    }
+}
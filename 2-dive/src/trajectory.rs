@@ -0,0 +1,102 @@
+//! Every step of a course, for debugging it rather than only multiplying where it ends up
+
+use std::io::{self, Write};
+
+use super::Sub;
+
+/// Where the sub was before the first move and after each one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    states: Vec<Sub>,
+}
+
+impl Trajectory {
+    /// `states` has to start with the sub at the surface
+    pub(crate) fn new(states: Vec<Sub>) -> Self {
+        Trajectory { states }
+    }
+
+    /// The sub at the start, then after move 1, move 2 and so on
+    pub fn states(&self) -> &[Sub] {
+        &self.states
+    }
+
+    pub fn end(&self) -> &Sub {
+        self.states
+            .last()
+            .expect("A trajectory always has its start")
+    }
+
+    /// The deepest the sub went, which is 0 if it never dived
+    pub fn max_depth(&self) -> i64 {
+        self.states.iter().map(|sub| sub.pos.1).max().unwrap_or(0)
+    }
+
+    /// The move, counted from 1, after which the sub first reached `depth` or deeper, or 0 if it
+    /// started there
+    pub fn first_reaching(&self, depth: i64) -> Option<usize> {
+        self.states.iter().position(|sub| sub.pos.1 >= depth)
+    }
+
+    /// How far the sub travelled in straight lines between each move
+    pub fn distance(&self) -> f64 {
+        self.states
+            .windows(2)
+            .map(|pair| {
+                let dx = (pair[1].pos.0 - pair[0].pos.0) as f64;
+                let dy = (pair[1].pos.1 - pair[0].pos.1) as f64;
+
+                dx.hypot(dy)
+            })
+            .sum()
+    }
+
+    /// Writes a `move,x,depth,aim` row for the start and every move, with a header
+    pub fn write_csv<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, "move,x,depth,aim")?;
+
+        for (i, sub) in self.states.iter().enumerate() {
+            writeln!(out, "{},{},{},{}", i, sub.pos.0, sub.pos.1, sub.aim)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, model::Aimed, parse_file};
+
+    #[test]
+    fn test_sample() {
+        let moves = parse_file(fixtures::SAMPLE).unwrap();
+        let trajectory = Sub::record(&Aimed, &moves).unwrap();
+
+        assert_eq!(trajectory.states().len(), moves.len() + 1);
+        assert_eq!(trajectory.end(), &Sub::from_moves(&moves).unwrap());
+        assert_eq!(trajectory.max_depth(), 60);
+        assert_eq!(trajectory.first_reaching(1), Some(3));
+        assert_eq!(trajectory.first_reaching(0), Some(0));
+        assert_eq!(trajectory.first_reaching(61), None);
+
+        // Forward 5 level, then 8 forward with an aim of 5 and 2 forward with an aim of 10
+        let expected = 5.0 + 8f64.hypot(40.0) + 2f64.hypot(20.0);
+        assert!((trajectory.distance() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_csv() {
+        let moves = parse_file("down 2\nforward 3\nup 1").unwrap();
+        let mut csv = Vec::new();
+        Sub::record(&Aimed, &moves)
+            .unwrap()
+            .write_csv(&mut csv)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "move,x,depth,aim\n0,0,0,0\n1,0,0,2\n2,3,6,2\n3,3,6,1\n"
+        );
+    }
+}
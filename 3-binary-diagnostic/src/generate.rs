@@ -0,0 +1,68 @@
+//! Random diagnostic reports, shaped like the real puzzle input
+
+use rand::{seq::SliceRandom, Rng};
+
+/// About as many reports as a real input
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// The report width that leaves room for `size` distinct reports, plus a few spare bits
+pub fn width(size: usize) -> usize {
+    let needed = usize::BITS - size.saturating_sub(1).leading_zeros();
+
+    (needed as usize + 3).min(63)
+}
+
+/// `size` distinct reports, `width` bits each
+///
+/// Wherever two or more reports share a prefix, some carry on with a 0 and some with a 1, so
+/// filtering for the ratings never runs out of candidates
+pub fn reports<R: Rng + ?Sized>(rng: &mut R, size: usize, width: usize) -> Vec<String> {
+    fn split<R: Rng + ?Sized>(
+        rng: &mut R,
+        prefix: &mut String,
+        count: u64,
+        width: usize,
+        out: &mut Vec<String>,
+    ) {
+        if count == 1 {
+            let mut report = prefix.clone();
+            report.extend((prefix.len()..width).map(|_| if rng.gen() { '1' } else { '0' }));
+            out.push(report);
+            return;
+        }
+
+        let capacity = 1u64 << (width - prefix.len() - 1);
+        let zeros = rng.gen_range(count.saturating_sub(capacity).max(1)..=capacity.min(count - 1));
+
+        for (digit, count) in [('0', zeros), ('1', count - zeros)] {
+            prefix.push(digit);
+            split(rng, prefix, count, width, out);
+            prefix.pop();
+        }
+    }
+
+    assert!(
+        width < 64 && (size as u64) <= 1 << width,
+        "{} distinct reports don't fit in {} bits",
+        size,
+        width
+    );
+
+    let mut reports = Vec::with_capacity(size);
+    if size > 0 {
+        split(rng, &mut String::new(), size as u64, width, &mut reports);
+    }
+    reports.shuffle(rng);
+
+    reports
+}
+
+pub fn render(reports: &[String]) -> String {
+    reports.iter().map(|r| format!("{}\n", r)).collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+
+    render(&reports(rng, size, width(size)))
+}
@@ -0,0 +1,513 @@
+use std::{error::Error, fmt};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+pub mod policy;
+pub mod trie;
+
+use policy::{MostOrLeastCommon, Policy};
+use trie::Trie;
+
+/// The widest reports that fit
+pub const MAX_WIDTH: usize = u128::BITS as usize;
+
+/// Diagnostic reports packed into the low `width` bits of integers, first digit most significant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reports {
+    pub width: usize,
+    pub values: Vec<u128>,
+}
+
+/// Why a list of reports isn't valid, naming the line, counted from 1, that's wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportError {
+    Empty,
+    Blank {
+        line: usize,
+    },
+    TooWide {
+        line: usize,
+        width: usize,
+    },
+    WrongWidth {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    NotBinary {
+        line: usize,
+        /// Counted from 1
+        column: usize,
+        found: char,
+    },
+}
+
+impl ReportError {
+    /// The line the error is on, if it's on one
+    pub fn line(&self) -> Option<usize> {
+        match *self {
+            ReportError::Empty => None,
+            ReportError::Blank { line }
+            | ReportError::TooWide { line, .. }
+            | ReportError::WrongWidth { line, .. }
+            | ReportError::NotBinary { line, .. } => Some(line),
+        }
+    }
+
+    fn message(&self) -> String {
+        match *self {
+            ReportError::Empty => "there are no reports".to_owned(),
+            ReportError::Blank { .. } => "expected a report, found a blank line".to_owned(),
+            ReportError::TooWide { width, .. } => format!(
+                "reports can be up to {} digits wide, this one has {}",
+                MAX_WIDTH, width
+            ),
+            ReportError::WrongWidth {
+                expected, found, ..
+            } => format!(
+                "expected a report {} digits wide, found {}",
+                expected, found
+            ),
+            ReportError::NotBinary { found, .. } => {
+                format!("expected a binary digit, found `{}`", found)
+            }
+        }
+    }
+
+    /// The same error pointing into `input`, which has to be the input it came from
+    pub fn locate(&self, input: &str) -> ParseError {
+        let line = match self.line().and_then(|line| input.lines().nth(line - 1)) {
+            Some(line) => line,
+            None => return ParseError::end_of_input(input, self.message()),
+        };
+
+        let span = match *self {
+            ReportError::NotBinary { column, .. } => line
+                .char_indices()
+                .nth(column - 1)
+                .map_or(line, |(i, c)| &line[i..i + c.len_utf8()]),
+            _ => line,
+        };
+
+        ParseError::at(input, span, self.message())
+    }
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line() {
+            Some(line) => write!(f, "Line {}: {}", line, self.message()),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl Error for ReportError {}
+
+impl Reports {
+    /// Parses one report of binary digits per line, all as wide as the first
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::validate(input).map_err(|e| e.locate(input))
+    }
+
+    /// Checks every line is a report of only binary digits, as wide as the first and no wider
+    /// than [`MAX_WIDTH`], before packing any of them
+    pub fn validate(input: &str) -> Result<Self, ReportError> {
+        let mut width = None;
+
+        for (i, report) in input.lines().enumerate() {
+            let line = i + 1;
+
+            if let Some((column, found)) = report
+                .chars()
+                .enumerate()
+                .find(|&(_, c)| c != '0' && c != '1')
+            {
+                return Err(ReportError::NotBinary {
+                    line,
+                    column: column + 1,
+                    found,
+                });
+            }
+
+            match (width, report.len()) {
+                (_, 0) => return Err(ReportError::Blank { line }),
+                (None, found) if found > MAX_WIDTH => {
+                    return Err(ReportError::TooWide { line, width: found })
+                }
+                (None, found) => width = Some(found),
+                (Some(expected), found) if found != expected => {
+                    return Err(ReportError::WrongWidth {
+                        line,
+                        expected,
+                        found,
+                    })
+                }
+                (Some(_), _) => {}
+            }
+        }
+
+        let width = width.ok_or(ReportError::Empty)?;
+        let values = input
+            .lines()
+            .map(|report| {
+                report
+                    .bytes()
+                    .fold(0, |value, digit| value << 1 | u128::from(digit == b'1'))
+            })
+            .collect();
+
+        Ok(Reports { width, values })
+    }
+
+    /// The mask for bit `i` counting from the first digit
+    fn bit(&self, i: usize) -> u128 {
+        1 << (self.width - 1 - i)
+    }
+
+    /// The most common digit at every position for gamma, and the least common for epsilon,
+    /// by the puzzle's rules
+    pub fn gamma_and_epsilon(&self) -> (u128, u128) {
+        self.gamma_and_epsilon_with(Policy::default())
+    }
+
+    pub fn gamma_and_epsilon_with(&self, policy: Policy) -> (u128, u128) {
+        (0..self.width)
+            .map(|i| self.bit(i))
+            .fold((0, 0), |(gamma, epsilon), bit| {
+                let ones = self.values.iter().filter(|&&v| v & bit != 0).count();
+                let zeros = self.values.len() - ones;
+                let pick = |filter_on| {
+                    if policy.picks_one(filter_on, ones, zeros) {
+                        bit
+                    } else {
+                        0
+                    }
+                };
+
+                (
+                    gamma | pick(MostOrLeastCommon::Most),
+                    epsilon | pick(MostOrLeastCommon::Least),
+                )
+            })
+    }
+
+    /// Filters the reports a digit at a time, keeping those with the most or least common digit
+    /// in that position until one is left, slow but obviously right
+    ///
+    /// Reports still left after the last digit are all the same, so any of them is the rating
+    fn rating(&self, filter_on: MostOrLeastCommon, policy: Policy) -> Result<u128> {
+        let mut candidates = self.values.clone();
+
+        for i in 0..self.width {
+            if candidates.len() <= 1 {
+                break;
+            }
+
+            let bit = self.bit(i);
+            let ones = candidates.iter().filter(|&&v| v & bit != 0).count();
+            let zeros = candidates.len() - ones;
+            let keep_ones = policy.picks_one(filter_on, ones, zeros);
+
+            candidates.retain(|&v| (v & bit != 0) == keep_ones);
+            if candidates.is_empty() {
+                return Err(unanimous(i));
+            }
+        }
+
+        candidates
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("There are no reports to pick from"))
+    }
+}
+
+/// The error for filtering out every report at digit `i`, counted from 0
+fn unanimous(i: usize) -> anyhow::Error {
+    anyhow!(
+        "Every report left has the same digit at position {}, so none has the least common one",
+        i + 1
+    )
+}
+
+/// Filters for both ratings without a trie
+pub fn brute_force_part2(reports: &Reports, policy: Policy) -> Result<u128> {
+    product(
+        reports.rating(MostOrLeastCommon::Most, policy)?,
+        reports.rating(MostOrLeastCommon::Least, policy)?,
+    )
+}
+
+fn product(a: u128, b: u128) -> Result<u128> {
+    a.checked_mul(b)
+        .ok_or_else(|| anyhow!("The product of {} and {} doesn't fit in a u128", a, b))
+}
+
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+    const NAME: &'static str = "binary-diagnostic";
+
+    type Input = Reports;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Reports::parse(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Self::Part1> {
+        let (gamma, epsilon) = reports.gamma_and_epsilon();
+
+        product(gamma, epsilon)
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Self::Part2> {
+        let trie = Trie::new(reports);
+        let policy = Policy::default();
+
+        product(
+            trie.rating(MostOrLeastCommon::Most, policy)?,
+            trie.rating(MostOrLeastCommon::Least, policy)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::property::{generated, same_answers};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    use policy::{TieBreak, Unanimous};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Reports::parse("00101\n11110\n").unwrap(),
+            Reports {
+                width: 5,
+                values: vec![0b00101, 0b11110]
+            }
+        );
+
+        let err = Reports::parse("101\n1101").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = Reports::parse("101\n1x1").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_validate() {
+        let wide = "1".repeat(129);
+
+        for (input, expected) in [
+            ("", ReportError::Empty),
+            ("101\n\n011", ReportError::Blank { line: 2 }),
+            (
+                &wide,
+                ReportError::TooWide {
+                    line: 1,
+                    width: 129,
+                },
+            ),
+            (
+                "101\n011\n1",
+                ReportError::WrongWidth {
+                    line: 3,
+                    expected: 3,
+                    found: 1,
+                },
+            ),
+            (
+                "101\n0é1",
+                ReportError::NotBinary {
+                    line: 2,
+                    column: 2,
+                    found: 'é',
+                },
+            ),
+            (
+                "1012\n10",
+                ReportError::NotBinary {
+                    line: 1,
+                    column: 4,
+                    found: '2',
+                },
+            ),
+        ] {
+            assert_eq!(Reports::validate(input).unwrap_err(), expected, "{}", input);
+        }
+
+        assert_eq!(
+            Reports::validate("101\n11").unwrap_err().to_string(),
+            "Line 2: expected a report 3 digits wide, found 2"
+        );
+        let err = Reports::parse("101\n0é1").unwrap_err();
+        assert_eq!((err.line, err.column, &err.text[..]), (2, 2, "é"));
+        let err = Reports::parse("\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (1, "expected a report, found a blank line")
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let reports = Reports::parse(fixtures::SAMPLE).unwrap();
+
+        assert_eq!(reports.gamma_and_epsilon(), (22, 9));
+        let policy = Policy::default();
+        assert_eq!(reports.rating(MostOrLeastCommon::Most, policy).unwrap(), 23);
+        assert_eq!(
+            reports.rating(MostOrLeastCommon::Least, policy).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_policies() {
+        let zeros_win = Policy {
+            tie_break: TieBreak::Zeros,
+            ..Policy::default()
+        };
+        let shared = Policy {
+            unanimous: Unanimous::Shared,
+            ..Policy::default()
+        };
+
+        let balanced = Reports::parse("10\n01").unwrap();
+        assert_eq!(balanced.gamma_and_epsilon(), (0b11, 0b00));
+        assert_eq!(balanced.gamma_and_epsilon_with(zeros_win), (0b00, 0b11));
+        assert_eq!(brute_force_part2(&balanced, Policy::default()).unwrap(), 2);
+        assert_eq!(brute_force_part2(&balanced, zeros_win).unwrap(), 2);
+
+        let agreeing = Reports::parse("10\n11").unwrap();
+        assert_eq!(agreeing.gamma_and_epsilon(), (0b11, 0b00));
+        assert_eq!(agreeing.gamma_and_epsilon_with(shared), (0b11, 0b10));
+        assert_eq!(
+            brute_force_part2(&agreeing, Policy::default())
+                .unwrap_err()
+                .to_string(),
+            "Every report left has the same digit at position 1, so none has the least common one"
+        );
+        assert_eq!(brute_force_part2(&agreeing, shared).unwrap(), 0b11 * 0b10);
+        assert_eq!(
+            Trie::new(&agreeing)
+                .rating(MostOrLeastCommon::Least, shared)
+                .unwrap(),
+            0b10
+        );
+    }
+
+    #[test]
+    fn test_wider_than_64_bits() {
+        let wide = |prefix: &str| format!("{}{}", prefix, "0".repeat(97));
+        let input = [wide("110"), wide("101"), wide("011")].join("\n");
+        let reports = Reports::parse(&input).unwrap();
+
+        assert_eq!(reports.width, 100);
+        assert_eq!(reports.gamma_and_epsilon(), (0b111 << 97, (1 << 97) - 1));
+        let trie = Trie::new(&reports);
+        assert_eq!(trie.oxygen_generator_rating().unwrap(), 0b110 << 97);
+        assert_eq!(trie.co2_scrubber_rating().unwrap(), 0b011 << 97);
+        assert!(BinaryDiagnostic::part1(&reports).is_err());
+
+        assert!(Reports::parse(&"1".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn test_running_out_of_reports() {
+        let nothing = Reports {
+            width: 3,
+            values: vec![],
+        };
+        assert!(BinaryDiagnostic::part2(&nothing).is_err());
+        assert_eq!(BinaryDiagnostic::part1(&nothing).unwrap(), 0);
+        assert!(BinaryDiagnostic::solve_part2("101\n101").is_err());
+        assert!(BinaryDiagnostic::solve_part2("10\n1").is_err());
+
+        // Copies of a report are left at the end of the filters, and are the same rating
+        let copies = Reports::parse("101\n101\n010").unwrap();
+        let shared = Policy {
+            unanimous: Unanimous::Shared,
+            ..Policy::default()
+        };
+        assert_eq!(brute_force_part2(&copies, shared).unwrap(), 0b101 * 0b010);
+        assert_eq!(
+            Trie::new(&copies)
+                .rating(MostOrLeastCommon::Most, shared)
+                .unwrap(),
+            0b101
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            198,
+            BinaryDiagnostic::solve_part1(fixtures::SAMPLE).unwrap()
+        );
+        assert_eq!(
+            2250414,
+            BinaryDiagnostic::solve_part1(fixtures::MY).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            230,
+            BinaryDiagnostic::solve_part2(fixtures::SAMPLE).unwrap()
+        );
+        assert_eq!(
+            6085575,
+            BinaryDiagnostic::solve_part2(fixtures::MY).unwrap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_trie_matches_brute_force(input in generated(generate::input, 1..200)) {
+            same_answers::<BinaryDiagnostic, _>(
+                &input,
+                |reports| brute_force_part2(reports, Policy::default()),
+                BinaryDiagnostic::part2,
+            )?;
+        }
+
+        #[test]
+        fn test_trie_matches_brute_force_on_any_reports(
+            reports in vec(0u128..16, 0..16),
+            ones_win in any::<bool>(),
+            absent in any::<bool>(),
+        ) {
+            let reports = Reports { width: 4, values: reports };
+            let policy = Policy {
+                tie_break: if ones_win { TieBreak::Ones } else { TieBreak::Zeros },
+                unanimous: if absent { Unanimous::Absent } else { Unanimous::Shared },
+            };
+            let trie = Trie::new(&reports);
+
+            for filter_on in [MostOrLeastCommon::Most, MostOrLeastCommon::Least] {
+                prop_assert_eq!(
+                    reports.rating(filter_on, policy).map_err(|e| e.to_string()),
+                    trie.rating(filter_on, policy).map_err(|e| e.to_string())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! The rules for picking the most and least common digit in a column, shared by the gamma and
+//! epsilon rates and the rating filters so they never disagree
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MostOrLeastCommon {
+    Most,
+    Least,
+}
+
+/// Which digit is the most common in a column split evenly, the other being the least common
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    #[default]
+    Ones,
+    Zeros,
+}
+
+/// Which digit is the least common in a column where every report has the same digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unanimous {
+    /// The digit no report has, so the least common filter is left with nothing
+    #[default]
+    Absent,
+    /// The digit every report has, so the least common filter keeps them all
+    Shared,
+}
+
+/// The default is the puzzle's reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Policy {
+    pub tie_break: TieBreak,
+    pub unanimous: Unanimous,
+}
+
+impl Policy {
+    /// Whether the most or least common digit of a column with `ones` ones and `zeros` zeros is
+    /// a 1
+    pub fn picks_one(&self, filter_on: MostOrLeastCommon, ones: usize, zeros: usize) -> bool {
+        let most_common_is_one = match ones.cmp(&zeros) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => self.tie_break == TieBreak::Ones,
+        };
+        let unanimous = (ones == 0) != (zeros == 0);
+
+        match filter_on {
+            MostOrLeastCommon::Most => most_common_is_one,
+            MostOrLeastCommon::Least if unanimous && self.unanimous == Unanimous::Shared => {
+                most_common_is_one
+            }
+            MostOrLeastCommon::Least => !most_common_is_one,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use MostOrLeastCommon::{Least, Most};
+
+    #[test]
+    fn test_picks_one() {
+        let puzzle = Policy::default();
+        let zeros_win = Policy {
+            tie_break: TieBreak::Zeros,
+            ..Policy::default()
+        };
+        let shared = Policy {
+            unanimous: Unanimous::Shared,
+            ..Policy::default()
+        };
+
+        for (policy, ones, zeros, most, least) in [
+            (puzzle, 3, 2, true, false),
+            (puzzle, 2, 3, false, true),
+            (puzzle, 2, 2, true, false),
+            (zeros_win, 2, 2, false, true),
+            (puzzle, 4, 0, true, false),
+            (shared, 4, 0, true, true),
+            (shared, 0, 4, false, false),
+            (shared, 0, 0, true, false),
+        ] {
+            assert_eq!(
+                policy.picks_one(Most, ones, zeros),
+                most,
+                "{:?} {} {}",
+                policy,
+                ones,
+                zeros
+            );
+            assert_eq!(
+                policy.picks_one(Least, ones, zeros),
+                least,
+                "{:?} {} {}",
+                policy,
+                ones,
+                zeros
+            );
+        }
+    }
+}
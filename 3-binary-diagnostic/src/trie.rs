@@ -0,0 +1,199 @@
+//! Reports in a binary trie, so questions about common prefixes are walks rather than scans
+
+use anyhow::{bail, Result};
+
+use super::{
+    policy::{MostOrLeastCommon, Policy},
+    unanimous, Reports,
+};
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// How many reports pass through this node
+    count: usize,
+    /// The nodes for a next digit of 0 and of 1
+    children: [Option<usize>; 2],
+}
+
+/// Every report, branching on each digit from the first, counting the reports under every node
+#[derive(Debug, Clone)]
+pub struct Trie {
+    width: usize,
+    /// The root is first
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    pub fn new(reports: &Reports) -> Self {
+        let mut trie = Trie {
+            width: reports.width,
+            nodes: vec![Node::default()],
+        };
+
+        for &report in &reports.values {
+            let mut node = 0;
+            trie.nodes[node].count += 1;
+
+            for i in 0..trie.width {
+                let digit = trie.digit(report, i);
+                node = match trie.nodes[node].children[digit] {
+                    Some(child) => child,
+                    None => {
+                        trie.nodes.push(Node::default());
+                        let child = trie.nodes.len() - 1;
+                        trie.nodes[node].children[digit] = Some(child);
+                        child
+                    }
+                };
+                trie.nodes[node].count += 1;
+            }
+        }
+
+        trie
+    }
+
+    /// Digit `i` of `report`, counting from the first
+    fn digit(&self, report: u128, i: usize) -> usize {
+        (report >> (self.width - 1 - i)) as usize & 1
+    }
+
+    fn count(&self, node: Option<usize>) -> usize {
+        node.map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// How many reports start with the first `len` digits of `prefix`, given as a `len` digit
+    /// number, which is none when `len` is wider than the reports
+    pub fn count_prefix(&self, prefix: u128, len: usize) -> usize {
+        if len > self.width {
+            return 0;
+        }
+
+        let mut node = Some(0);
+
+        for i in 0..len {
+            let digit = (prefix >> (len - 1 - i)) as usize & 1;
+            node = node.and_then(|node| self.nodes[node].children[digit]);
+        }
+
+        self.count(node)
+    }
+
+    /// The prefix of `len` digits that starts the most reports, and how many it starts, picking
+    /// the smallest prefix on ties
+    ///
+    /// Like [`Trie::count_prefix`], nothing starts with a prefix longer than the reports
+    pub fn most_common_prefix(&self, len: usize) -> Option<(u128, usize)> {
+        if len > self.width {
+            return None;
+        }
+        let mut best: Option<(u128, usize)> = None;
+        let mut stack = vec![(0, 0u128, 0)];
+
+        while let Some((node, prefix, depth)) = stack.pop() {
+            let count = self.nodes[node].count;
+
+            if depth == len {
+                if count > 0 && best.is_none_or(|(_, most)| count > most) {
+                    best = Some((prefix, count));
+                }
+                continue;
+            }
+
+            // Ones first, so zeros come off the stack first and win ties
+            for digit in [1, 0] {
+                if let Some(child) = self.nodes[node].children[digit] {
+                    stack.push((child, prefix << 1 | digit as u128, depth + 1));
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Walks from the root to the one report left after keeping the most or least common next
+    /// digit at each node
+    ///
+    /// A leaf with more than one report under it holds copies of the same report, so that's the
+    /// rating
+    pub fn rating(&self, filter_on: MostOrLeastCommon, policy: Policy) -> Result<u128> {
+        if self.nodes[0].count == 0 {
+            bail!("There are no reports to pick from");
+        }
+
+        let mut node = 0;
+        let mut rating = 0;
+
+        for i in 0..self.width {
+            let [zero, one] = self.nodes[node].children;
+            let (zeros, ones) = (self.count(zero), self.count(one));
+
+            let keep_one = if self.nodes[node].count == 1 {
+                ones == 1
+            } else {
+                policy.picks_one(filter_on, ones, zeros)
+            };
+
+            node = match (keep_one, zero, one) {
+                (true, _, Some(child)) | (false, Some(child), _) => child,
+                _ => return Err(unanimous(i)),
+            };
+            rating = rating << 1 | keep_one as u128;
+        }
+
+        Ok(rating)
+    }
+
+    pub fn oxygen_generator_rating(&self) -> Result<u128> {
+        self.rating(MostOrLeastCommon::Most, Policy::default())
+    }
+
+    pub fn co2_scrubber_rating(&self) -> Result<u128> {
+        self.rating(MostOrLeastCommon::Least, Policy::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_prefixes() {
+        let trie = Trie::new(&Reports::parse(fixtures::SAMPLE).unwrap());
+
+        assert_eq!(trie.count_prefix(0, 0), 12);
+        assert_eq!(trie.count_prefix(0b1, 1), 7);
+        assert_eq!(trie.count_prefix(0b101, 3), 3);
+        assert_eq!(trie.count_prefix(0b10111, 5), 1);
+        assert_eq!(trie.count_prefix(0b11111, 5), 0);
+
+        assert_eq!(trie.most_common_prefix(3), Some((0b101, 3)));
+        assert_eq!(trie.most_common_prefix(0), Some((0, 12)));
+    }
+
+    #[test]
+    fn test_prefixes_longer_than_the_reports() {
+        let trie = Trie::new(&Reports::parse("10110\n01011").unwrap());
+
+        assert_eq!(trie.count_prefix(0b1011000, 7), 0);
+        assert_eq!(trie.count_prefix(0b1011, 129), 0);
+        assert_eq!(trie.count_prefix(0b10110, 5), 1);
+    }
+
+    #[test]
+    fn test_most_common_prefixes_longer_than_the_reports() {
+        let trie = Trie::new(&Reports::parse("10110\n01011").unwrap());
+
+        assert_eq!(trie.most_common_prefix(6), None);
+        assert_eq!(trie.most_common_prefix(129), None);
+        assert_eq!(trie.most_common_prefix(5), Some((0b01011, 1)));
+    }
+
+    #[test]
+    fn test_ratings() {
+        let trie = Trie::new(&Reports::parse(fixtures::SAMPLE).unwrap());
+
+        assert_eq!(trie.oxygen_generator_rating().unwrap(), 23);
+        assert_eq!(trie.co2_scrubber_rating().unwrap(), 10);
+    }
+}
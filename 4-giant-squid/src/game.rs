@@ -0,0 +1,201 @@
+//! Playing the called numbers against every card under a chosen set of win rules
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use super::{
+    rules::{Columns, Rows, WinRule},
+    Board,
+};
+
+/// A card winning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Which card, counted from 0
+    pub card: usize,
+    /// How many numbers had been called, including the winning one
+    pub calls: usize,
+    pub score: u64,
+}
+
+/// The rules a game is played by, where a card wins as soon as any one of them does
+pub struct Game {
+    rules: Vec<Box<dyn WinRule>>,
+}
+
+impl Default for Game {
+    /// The puzzle's game, won by a complete row or column
+    fn default() -> Self {
+        Game::new(vec![Box::new(Rows), Box::new(Columns)])
+    }
+}
+
+impl Game {
+    pub fn new(rules: Vec<Box<dyn WinRule>>) -> Self {
+        Game { rules }
+    }
+
+    /// Every card's win in the order they happen, cards winning on the same call in card order
+    ///
+    /// Cards that never win are left out
+    pub fn wins_in_order(&self, numbers: &[u64], boards: &[Board]) -> Result<Vec<Win>> {
+        self.play(numbers, boards, boards.len())
+    }
+
+    /// The first `wanted` wins in the order they happen, stopping calling numbers once they have
+    fn play(&self, numbers: &[u64], boards: &[Board], wanted: usize) -> Result<Vec<Win>> {
+        let mut boards = boards.to_vec();
+        let mut won = vec![false; boards.len()];
+        let mut wins = Vec::new();
+
+        // So each call only visits the cards it's on, in card order
+        let mut cards_with: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (card, board) in boards.iter().enumerate() {
+            for num in board.numbers() {
+                cards_with.entry(num).or_default().push(card);
+            }
+        }
+
+        for (i, &n) in numbers.iter().enumerate() {
+            for &card in cards_with.get(&n).into_iter().flatten() {
+                let board = &mut boards[card];
+
+                if !won[card] && board.call(n, &self.rules) {
+                    won[card] = true;
+                    wins.push(Win {
+                        card,
+                        calls: i + 1,
+                        score: board.score(n)?,
+                    });
+
+                    if wins.len() == wanted {
+                        return Ok(wins);
+                    }
+                }
+            }
+        }
+
+        Ok(wins)
+    }
+
+    /// The first card to win, the earliest card winning ties
+    pub fn first_win(&self, numbers: &[u64], boards: &[Board]) -> Result<Win> {
+        self.play(numbers, boards, 1)?
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("No board won"))
+    }
+
+    /// The last card to win, as long as every card wins and no two of them win last together
+    pub fn last_win(&self, numbers: &[u64], boards: &[Board]) -> Result<Win> {
+        let wins = self.wins_in_order(numbers, boards)?;
+
+        match wins[..] {
+            [.., before, last] if before.calls == last.calls => Err(anyhow!("No board won last")),
+            [.., last] if wins.len() == boards.len() => Ok(last),
+            _ => Err(anyhow!("No board won last")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        fixtures, generate, parse_file,
+        rules::{Blackout, Columns, Corners, Diagonals, Mask, Rows, X},
+    };
+
+    #[test]
+    fn test_sample() {
+        let (numbers, boards) = parse_file(fixtures::SAMPLE).unwrap();
+        let game = Game::default();
+
+        assert_eq!(
+            game.first_win(&numbers, &boards).unwrap(),
+            Win {
+                card: 2,
+                calls: 12,
+                score: 4512
+            }
+        );
+        assert_eq!(game.last_win(&numbers, &boards).unwrap().card, 1);
+    }
+
+    #[test]
+    fn test_other_rules() {
+        let (numbers, boards) = parse_file(fixtures::SAMPLE).unwrap();
+        let calls = |game: Game| -> Vec<(usize, usize)> {
+            let wins = game.wins_in_order(&numbers, &boards).unwrap();
+
+            wins.iter().map(|win| (win.card, win.calls)).collect()
+        };
+
+        // The third card's 4, 9, 23, 11 and 2 run corner to corner
+        assert_eq!(
+            calls(Game::new(vec![Box::new(Diagonals)])),
+            [(2, 8), (1, 20), (0, 24)]
+        );
+        assert_eq!(
+            calls(Game::new(vec![Box::new(Blackout)])),
+            [(1, 25), (2, 26), (0, 27)]
+        );
+        assert_eq!(
+            calls(Game::new(vec![Box::new(Blackout), Box::new(Diagonals)]))[0],
+            (2, 8)
+        );
+    }
+
+    #[test]
+    fn test_marks_are_counted_as_they_happen() {
+        type Rules = Vec<Box<dyn WinRule>>;
+        let rule_sets: Vec<fn() -> Rules> = vec![
+            || vec![Box::new(Rows), Box::new(Columns)],
+            || vec![Box::new(Diagonals), Box::new(Corners)],
+            || vec![Box::new(X)],
+            || vec![Box::new(Blackout)],
+            || {
+                Mask::parse("..X..\n.X.X.\nX...X\n.X.X.\n..X..")
+                    .unwrap()
+                    .into_iter()
+                    .map(|m| Box::new(m) as Box<dyn WinRule>)
+                    .collect()
+            },
+        ];
+
+        for seed in 0..5 {
+            let input = generate::input(&mut StdRng::seed_from_u64(seed), 20);
+            let (numbers, boards) = parse_file(&input).unwrap();
+
+            for rules in &rule_sets {
+                let game = Game::new(rules());
+                let wins = game.wins_in_order(&numbers, &boards).unwrap();
+                // Stopping early doesn't change which card wins first
+                assert_eq!(
+                    game.first_win(&numbers, &boards).ok(),
+                    wins.first().copied()
+                );
+
+                // Looking over every card after every call instead
+                let rules = rules();
+                let mut boards = boards.clone();
+                let mut expected = Vec::new();
+                for (i, &n) in numbers.iter().enumerate() {
+                    for (card, board) in boards.iter_mut().enumerate() {
+                        let won = rules.iter().any(|rule| rule.wins(board));
+                        board.mark(n);
+                        if !won && rules.iter().any(|rule| rule.wins(board)) {
+                            expected.push((card, i + 1));
+                        }
+                    }
+                }
+
+                let wins: Vec<_> = wins.iter().map(|win| (win.card, win.calls)).collect();
+                assert_eq!(wins, expected, "seed {}", seed);
+            }
+        }
+    }
+}
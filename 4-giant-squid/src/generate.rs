@@ -0,0 +1,77 @@
+//! Random bingo games, numbers and cards shaped like the real puzzle input
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+
+/// About as many boards as a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Every number on the boards comes from `0..NUMBERS`, and all of them get drawn
+pub const NUMBERS: u64 = 100;
+
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    pub numbers: Vec<u64>,
+    pub boards: Vec<[[u64; 5]; 5]>,
+}
+
+/// How many numbers get drawn before `board` wins
+fn draws_to_win(board: &[[u64; 5]; 5], drawn_at: &[usize]) -> usize {
+    let rows = board.iter().map(|row| row.to_vec());
+    let columns = (0..5).map(|i| board.iter().map(|row| row[i]).collect_vec());
+
+    rows.chain(columns)
+        .map(|line| line.iter().map(|&n| drawn_at[n as usize]).max().unwrap())
+        .min()
+        .unwrap()
+}
+
+/// `size` boards and a draw order that covers every number, so every board wins eventually
+///
+/// Like in the puzzle, one board wins first and one board wins last without any ties
+pub fn bingo<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Bingo {
+    let pool = (0..NUMBERS).collect_vec();
+
+    loop {
+        let mut numbers = pool.clone();
+        numbers.shuffle(rng);
+
+        let boards = (0..size)
+            .map(|_| {
+                let mut cells = pool.choose_multiple(rng, 25);
+
+                [(); 5].map(|_| [(); 5].map(|_| *cells.next().unwrap()))
+            })
+            .collect_vec();
+
+        let mut drawn_at = vec![0; NUMBERS as usize];
+        for (i, &n) in numbers.iter().enumerate() {
+            drawn_at[n as usize] = i;
+        }
+        let wins = boards.iter().map(|b| draws_to_win(b, &drawn_at)).counts();
+        let unique = |draw: Option<&usize>| draw.is_none_or(|draw| wins[draw] == 1);
+
+        if unique(wins.keys().min()) && unique(wins.keys().max()) {
+            return Bingo { numbers, boards };
+        }
+    }
+}
+
+pub fn render(bingo: &Bingo) -> String {
+    let mut s = bingo.numbers.iter().join(",");
+    s.push('\n');
+
+    for board in &bingo.boards {
+        s.push('\n');
+        for row in board {
+            s.push_str(&row.iter().map(|n| format!("{:>2}", n)).join(" "));
+            s.push('\n');
+        }
+    }
+
+    s
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&bingo(rng, size))
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::parse_at, ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod game;
+pub mod generate;
+pub mod rules;
+
+use game::Game;
+use rules::WinRule;
+
+#[derive(Debug, Copy, Clone)]
+struct Cell {
+    num: u64,
+    marked: bool,
+    /// The next cell with the same number, for cards that repeat one
+    next: Option<usize>,
+}
+
+/// A square bingo card of any size, counting its marks as they're made so win rules don't have
+/// to look over the whole card
+#[derive(Debug, Clone)]
+pub struct Board {
+    size: usize,
+    /// Row by row
+    cells: Vec<Cell>,
+    /// The first cell each number is in, so calling it doesn't scan the card
+    index: HashMap<u64, usize>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    /// The top left to bottom right diagonal, then top right to bottom left
+    diagonal_marks: [usize; 2],
+    marks: usize,
+}
+
+impl Board {
+    /// Parses the `card` section of `input`, which should be as many rows as its first row has
+    /// numbers, `index` counting the cards from 1 for errors
+    fn parse(input: &str, index: usize, card: &str) -> Result<Self, ParseError> {
+        let mut rows = card.lines().peekable();
+        let size = match rows.peek() {
+            Some(first) => first.split_whitespace().count(),
+            None => 0,
+        };
+        if size == 0 {
+            return Err(ParseError::at(
+                input,
+                card,
+                format!("expected numbers for card {}", index),
+            ));
+        }
+
+        let mut nums = Vec::with_capacity(size * size);
+        let mut height = 0;
+        for row in rows {
+            let row_nums = row
+                .split_whitespace()
+                .map(|s| parse_at(input, s, "a bingo number"))
+                .collect::<Result<Vec<u64>, _>>()?;
+
+            if row_nums.len() != size {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!(
+                        "expected a row of {} numbers in card {}, found {}",
+                        size,
+                        index,
+                        row_nums.len()
+                    ),
+                ));
+            }
+
+            nums.extend(row_nums);
+            height += 1;
+        }
+
+        if height != size {
+            return Err(ParseError::at(
+                input,
+                card,
+                format!(
+                    "expected card {} to have {} rows like it has columns, found {}",
+                    index, size, height
+                ),
+            ));
+        }
+
+        Ok(Self::new(size, nums))
+    }
+
+    /// A card of `nums` row by row, which has to be `size` rows of `size`
+    fn new(size: usize, nums: Vec<u64>) -> Self {
+        let mut cells: Vec<_> = nums
+            .into_iter()
+            .map(|num| Cell {
+                num,
+                marked: false,
+                next: None,
+            })
+            .collect();
+
+        // Backwards, so each number ends up pointing at its first cell
+        let mut index = HashMap::with_capacity(cells.len());
+        for i in (0..cells.len()).rev() {
+            cells[i].next = index.insert(cells[i].num, i);
+        }
+
+        Board {
+            size,
+            cells,
+            index,
+            row_marks: vec![0; size],
+            column_marks: vec![0; size],
+            diagonal_marks: [0; 2],
+            marks: 0,
+        }
+    }
+
+    /// How many numbers are in each row and column
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_marked(&self, row: usize, col: usize) -> bool {
+        self.cells[row * self.size + col].marked
+    }
+
+    pub fn marked_in_row(&self, row: usize) -> usize {
+        self.row_marks[row]
+    }
+
+    pub fn marked_in_column(&self, col: usize) -> usize {
+        self.column_marks[col]
+    }
+
+    /// How many cells are marked on the top left to bottom right diagonal, then the top right to
+    /// bottom left one
+    pub fn marked_on_diagonals(&self) -> [usize; 2] {
+        self.diagonal_marks
+    }
+
+    pub fn marked(&self) -> usize {
+        self.marks
+    }
+
+    /// Every number on the card once, in no particular order
+    fn numbers(&self) -> impl Iterator<Item = u64> + '_ {
+        self.index.keys().copied()
+    }
+
+    /// The board's final score if `last_called` won it, ie the sum of its unmarked numbers times
+    /// `last_called`
+    fn score(&self, last_called: u64) -> Result<u64> {
+        self.cells
+            .iter()
+            .filter(|cell| !cell.marked)
+            .try_fold(0u64, |sum, cell| sum.checked_add(cell.num))
+            .and_then(|sum| sum.checked_mul(last_called))
+            .ok_or_else(|| anyhow!("The winning board's score doesn't fit in a u64"))
+    }
+
+    /// The cells with `num`, first to last
+    fn cells_with(&self, num: u64) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.index.get(&num).copied(), |&i| self.cells[i].next)
+    }
+
+    fn mark(&mut self, num: u64) {
+        let mut next = self.index.get(&num).copied();
+
+        while let Some(i) = next {
+            let cell = &mut self.cells[i];
+            next = cell.next;
+            if cell.marked {
+                continue;
+            }
+            cell.marked = true;
+
+            let (row, col) = (i / self.size, i % self.size);
+            self.row_marks[row] += 1;
+            self.column_marks[col] += 1;
+            if row == col {
+                self.diagonal_marks[0] += 1;
+            }
+            if row + col == self.size - 1 {
+                self.diagonal_marks[1] += 1;
+            }
+            self.marks += 1;
+        }
+    }
+
+    /// Marks `num`, returning whether that won the card under any of `rules`, which it can't
+    /// have already
+    fn call(&mut self, num: u64, rules: &[Box<dyn WinRule>]) -> bool {
+        self.mark(num);
+
+        self.cells_with(num).any(|i| {
+            let (row, col) = (i / self.size, i % self.size);
+
+            rules.iter().any(|rule| rule.completed_by(self, row, col))
+        })
+    }
+}
+
+fn parse_file(s: &str) -> Result<(Vec<u64>, Vec<Board>), ParseError> {
+    let mut split = s.split("\n\n");
+    let chosen_numbers = split
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|n| parse_at(s, n, "a chosen number"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let boards = split
+        .enumerate()
+        .map(|(i, card)| Board::parse(s, i + 1, card))
+        .collect::<Result<Vec<Board>, _>>()?;
+
+    Ok((chosen_numbers, boards))
+}
+
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+    const NAME: &'static str = "giant-squid";
+
+    type Input = (Vec<u64>, Vec<Board>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_file(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let (chosen_numbers, boards) = input;
+
+        Ok(Game::default().first_win(chosen_numbers, boards)?.score)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let (chosen_numbers, boards) = input;
+
+        Ok(Game::default().last_win(chosen_numbers, boards)?.score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(4512, GiantSquid::solve_part1(fixtures::SAMPLE).unwrap());
+        assert_eq!(38594, GiantSquid::solve_part1(fixtures::MY).unwrap());
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(1924, GiantSquid::solve_part2(fixtures::SAMPLE).unwrap());
+        assert_eq!(21184, GiantSquid::solve_part2(fixtures::MY).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_file("7,4,x9\n\n1 2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "x9"));
+
+        let board = "1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19\n21 22 23 24 25";
+        let err = parse_file(&format!("1,2\n\n{}\n", board)).unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(
+            err.message,
+            "expected a row of 5 numbers in card 1, found 4"
+        );
+
+        let err = parse_file("1,2\n\n1 2\n3 4\n\n1 2 3 4 5\n6 7 8 9 10\n").unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(
+            err.message,
+            "expected card 2 to have 5 rows like it has columns, found 2"
+        );
+
+        let err = parse_file("1,2\n\n1 2\n3 4\n\n\n").unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(err.message, "expected numbers for card 2");
+    }
+
+    #[test]
+    fn test_mixed_sizes() {
+        let input = "5,1,2,4,7\n\n1 2 3\n4 5 6\n7 8 9\n\n5\n\n1 2\n3 4\n";
+        let (numbers, boards) = parse_file(input).unwrap();
+
+        assert_eq!(numbers, [5, 1, 2, 4, 7]);
+        assert_eq!(
+            boards.iter().map(Board::size).collect::<Vec<_>>(),
+            [3, 1, 2]
+        );
+        // The 1 by 1 card wins straight away, then the 2 by 2's top row and the 3 by 3's first column
+        assert_eq!(GiantSquid::solve_part1(input).unwrap(), 0);
+        assert_eq!(GiantSquid::solve_part2(input).unwrap(), (3 + 6 + 8 + 9) * 7);
+    }
+}
@@ -0,0 +1,325 @@
+//! Ways to win a card, so a game can play by rules other than the puzzle's rows and columns
+//!
+//! Custom patterns are drawn as masks, `X` for a cell that has to be marked and `.` for one that
+//! doesn't, with blank lines between masks and `#` starting a comment line
+//!
+//! ```text
+//! # Plus
+//! ..X..
+//! ..X..
+//! XXXXX
+//! ..X..
+//! ..X..
+//! ```
+
+use aoc_common::ParseError;
+
+use super::Board;
+
+/// A pattern of marked cells that wins a card
+///
+/// Implement this for new patterns and play them with [`Game::new`](super::game::Game::new)
+pub trait WinRule {
+    fn wins(&self, board: &Board) -> bool;
+
+    /// Whether marking the cell at `row`, `col` has just won a card that hadn't been won before
+    ///
+    /// Games ask this after every mark rather than [`WinRule::wins`], so it's worth answering
+    /// from the card's mark counts and the cell alone where a rule can
+    fn completed_by(&self, board: &Board, _row: usize, _col: usize) -> bool {
+        self.wins(board)
+    }
+}
+
+/// Any complete row, the puzzle's rule along with [`Columns`]
+pub struct Rows;
+
+impl WinRule for Rows {
+    fn wins(&self, board: &Board) -> bool {
+        (0..board.size()).any(|row| board.marked_in_row(row) == board.size())
+    }
+
+    fn completed_by(&self, board: &Board, row: usize, _col: usize) -> bool {
+        board.marked_in_row(row) == board.size()
+    }
+}
+
+/// Any complete column
+pub struct Columns;
+
+impl WinRule for Columns {
+    fn wins(&self, board: &Board) -> bool {
+        (0..board.size()).any(|col| board.marked_in_column(col) == board.size())
+    }
+
+    fn completed_by(&self, board: &Board, _row: usize, col: usize) -> bool {
+        board.marked_in_column(col) == board.size()
+    }
+}
+
+/// Either complete diagonal
+pub struct Diagonals;
+
+impl WinRule for Diagonals {
+    fn wins(&self, board: &Board) -> bool {
+        let [leading, trailing] = full_diagonals(board);
+
+        leading || trailing
+    }
+
+    fn completed_by(&self, board: &Board, row: usize, col: usize) -> bool {
+        on_diagonal(board, row, col) && self.wins(board)
+    }
+}
+
+/// Both diagonals at once
+pub struct X;
+
+impl WinRule for X {
+    fn wins(&self, board: &Board) -> bool {
+        full_diagonals(board) == [true, true]
+    }
+
+    fn completed_by(&self, board: &Board, row: usize, col: usize) -> bool {
+        on_diagonal(board, row, col) && self.wins(board)
+    }
+}
+
+/// The four corner cells
+pub struct Corners;
+
+impl WinRule for Corners {
+    fn wins(&self, board: &Board) -> bool {
+        let last = board.size() - 1;
+
+        [(0, 0), (0, last), (last, 0), (last, last)]
+            .iter()
+            .all(|&(row, col)| board.is_marked(row, col))
+    }
+
+    fn completed_by(&self, board: &Board, row: usize, col: usize) -> bool {
+        let last = board.size() - 1;
+
+        (row == 0 || row == last) && (col == 0 || col == last) && self.wins(board)
+    }
+}
+
+/// Every cell on the card
+pub struct Blackout;
+
+impl WinRule for Blackout {
+    fn wins(&self, board: &Board) -> bool {
+        board.marked() == board.size() * board.size()
+    }
+}
+
+/// Whether each diagonal, top left to bottom right then top right to bottom left, is complete
+fn full_diagonals(board: &Board) -> [bool; 2] {
+    board
+        .marked_on_diagonals()
+        .map(|marked| marked == board.size())
+}
+
+fn on_diagonal(board: &Board, row: usize, col: usize) -> bool {
+    row == col || row + col == board.size() - 1
+}
+
+/// A hand drawn pattern, which only wins cards of its own size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    pub name: Option<String>,
+    size: usize,
+    /// Row by row, whether each cell has to be marked
+    cells: Vec<bool>,
+}
+
+impl Mask {
+    /// Parses the masks in `input`, each a square of `X` and `.` with at least one `X`, and an
+    /// optional `#` comment before it for a name
+    pub fn parse(input: &str) -> Result<Vec<Mask>, ParseError> {
+        let mut masks = Vec::new();
+        let mut lines = input.lines().peekable();
+
+        while lines.peek().is_some() {
+            // The `#` line too, to point at when no mask follows it
+            let mut name: Option<(&str, String)> = None;
+            let mut rows = Vec::new();
+
+            for line in lines.by_ref() {
+                let line = line.trim_end();
+
+                if line.is_empty() {
+                    if name.is_some() || !rows.is_empty() {
+                        break;
+                    }
+                } else if let Some(comment) = line.strip_prefix('#') {
+                    if rows.is_empty() {
+                        name = Some((line, comment.trim().to_owned()));
+                    }
+                } else {
+                    rows.push(line);
+                }
+            }
+
+            match (name, rows.is_empty()) {
+                (Some((line, _)), true) => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "expected a mask after its name",
+                    ))
+                }
+                (None, true) => continue,
+                (name, false) => {
+                    masks.push(Mask::from_rows(input, name.map(|(_, name)| name), &rows)?)
+                }
+            }
+        }
+
+        Ok(masks)
+    }
+
+    fn from_rows(input: &str, name: Option<String>, rows: &[&str]) -> Result<Mask, ParseError> {
+        let size = rows.len();
+        let mut cells = Vec::with_capacity(size * size);
+
+        for row in rows {
+            if row.chars().count() != size {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!(
+                        "expected a row of {} cells to match the mask's {} rows, found {}",
+                        size,
+                        size,
+                        row.chars().count()
+                    ),
+                ));
+            }
+
+            for (i, c) in row.char_indices() {
+                cells.push(match c {
+                    'X' => true,
+                    '.' => false,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            &row[i..i + c.len_utf8()],
+                            format!("expected `X` or `.`, found `{}`", c),
+                        ))
+                    }
+                });
+            }
+        }
+
+        if !cells.contains(&true) {
+            return Err(ParseError::at(
+                input,
+                rows[0],
+                "expected at least one `X` in the mask, it'd win every card",
+            ));
+        }
+
+        Ok(Mask { name, size, cells })
+    }
+}
+
+impl WinRule for Mask {
+    fn completed_by(&self, board: &Board, row: usize, col: usize) -> bool {
+        board.size() == self.size && self.cells[row * self.size + col] && self.wins(board)
+    }
+
+    fn wins(&self, board: &Board) -> bool {
+        board.size() == self.size
+            && self
+                .cells
+                .iter()
+                .enumerate()
+                .all(|(i, &needed)| !needed || board.is_marked(i / self.size, i % self.size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3 by 3 card of 1 to 9 with `marked` called
+    fn board(marked: &[u64]) -> Board {
+        let input = "1 2 3\n4 5 6\n7 8 9";
+        let mut board = Board::parse(input, 1, input).unwrap();
+        for &n in marked {
+            board.mark(n);
+        }
+
+        board
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules: [(&str, &dyn WinRule); 6] = [
+            ("rows", &Rows),
+            ("columns", &Columns),
+            ("diagonals", &Diagonals),
+            ("x", &X),
+            ("corners", &Corners),
+            ("blackout", &Blackout),
+        ];
+
+        for (marked, winners) in [
+            (&[4, 5, 6][..], &["rows"][..]),
+            (&[2, 5, 8], &["columns"]),
+            (&[3, 5, 7], &["diagonals"]),
+            (&[1, 3, 5, 7, 9], &["diagonals", "x", "corners"]),
+            (&[1, 3, 7, 9], &["corners"]),
+            (
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+                &["rows", "columns", "diagonals", "x", "corners", "blackout"],
+            ),
+            (&[1, 2, 4, 6, 8], &[]),
+        ] {
+            let board = board(marked);
+            let won: Vec<_> = rules
+                .iter()
+                .filter(|(_, rule)| rule.wins(&board))
+                .map(|(name, _)| *name)
+                .collect();
+
+            assert_eq!(won, winners, "{:?}", marked);
+        }
+    }
+
+    #[test]
+    fn test_masks() {
+        let masks = Mask::parse("# Plus\n.X.\nXXX\n.X.\n\n\nX.\n.X\n").unwrap();
+
+        assert_eq!(masks.len(), 2);
+        assert_eq!(masks[0].name.as_deref(), Some("Plus"));
+        assert_eq!(masks[1].name, None);
+
+        assert!(masks[0].wins(&board(&[2, 4, 5, 6, 8])));
+        assert!(!masks[0].wins(&board(&[2, 4, 5, 6])));
+        // Only for 2 by 2 cards
+        assert!(!masks[1].wins(&board(&[1, 2, 3, 4, 5, 6, 7, 8, 9])));
+
+        for (masks, location, message) in [
+            (
+                "X.\nXX.",
+                (2, 1),
+                "expected a row of 2 cells to match the mask's 2 rows, found 3",
+            ),
+            ("X.\n.O", (2, 2), "expected `X` or `.`, found `O`"),
+            ("# Nothing\n", (1, 1), "expected a mask after its name"),
+            ("# Nothing\n\nX", (1, 1), "expected a mask after its name"),
+            (
+                "X\n\n..\n..",
+                (3, 1),
+                "expected at least one `X` in the mask, it'd win every card",
+            ),
+        ] {
+            let err = Mask::parse(masks).unwrap_err();
+
+            assert_eq!((err.line, err.column), location, "{}", err);
+            assert_eq!(err.message, message);
+        }
+    }
+}
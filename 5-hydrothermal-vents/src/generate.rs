@@ -0,0 +1,67 @@
+//! Random vent surveys, shaped like the real puzzle input
+
+use rand::Rng;
+
+use super::{Line, Position};
+
+/// About as many lines as a real input
+pub const DEFAULT_SIZE: usize = 500;
+
+/// `size` horizontal, vertical and 45 degree lines, spread over a map that grows with `size` up
+/// to the real input's 1000x1000
+pub fn lines<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<Line> {
+    let extent = (size as u32 * 2).clamp(10, 1000);
+
+    (0..size)
+        .map(|_| {
+            let start = Position {
+                x: rng.gen_range(0..extent),
+                y: rng.gen_range(0..extent),
+            };
+            let other = |rng: &mut R, from: u32| loop {
+                let to = rng.gen_range(0..extent);
+                if to != from {
+                    break to;
+                }
+            };
+
+            let end = match rng.gen_range(0..3) {
+                0 => Position {
+                    x: other(rng, start.x),
+                    ..start
+                },
+                1 => Position {
+                    y: other(rng, start.y),
+                    ..start
+                },
+                _ => {
+                    let x = other(rng, start.x);
+                    let len = x.abs_diff(start.x);
+                    let y = if start.y >= len && (start.y + len >= extent || rng.gen()) {
+                        start.y - len
+                    } else if start.y + len < extent {
+                        start.y + len
+                    } else {
+                        // Too long to fit either way, so flatten it into a horizontal line
+                        start.y
+                    };
+
+                    Position { x, y }
+                }
+            };
+
+            Line { start, end }
+        })
+        .collect()
+}
+
+pub fn render(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|l| format!("{},{} -> {},{}\n", l.start.x, l.start.y, l.end.x, l.end.y))
+        .collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&lines(rng, size))
+}
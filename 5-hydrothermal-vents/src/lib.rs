@@ -0,0 +1,212 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use anyhow::Result;
+use aoc_common::{parse::parse_at, ParseError, Solution};
+use itertools::Itertools;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Position {
+    x: u32,
+    y: u32,
+}
+
+impl Position {
+    /// Parses `x,y` from the `pos` slice of `input`
+    fn parse(input: &str, pos: &str) -> Result<Self, ParseError> {
+        let pos = pos.trim();
+        let (x, y) = pos
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(input, pos, "expected a position like `3,4`"))?;
+
+        Ok(Position {
+            x: parse_at(input, x, "a coordinate")?,
+            y: parse_at(input, y, "a coordinate")?,
+        })
+    }
+
+    fn next_towards(&self, other: &Position) -> Position {
+        let mut x = self.x;
+        let mut y = self.y;
+
+        match self.y.cmp(&other.y) {
+            Ordering::Less => y += 1,
+            Ordering::Greater => y -= 1,
+            Ordering::Equal => {}
+        }
+
+        match self.x.cmp(&other.x) {
+            Ordering::Less => x += 1,
+            Ordering::Greater => x -= 1,
+            Ordering::Equal => {}
+        }
+
+        Position { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    start: Position,
+    end: Position,
+}
+
+impl Line {
+    /// Parses one `line` of `input`, ie `0,9 -> 5,9`
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (start, end) = line
+            .split_once("->")
+            .ok_or_else(|| ParseError::at(input, line, "expected a line like `0,9 -> 5,9`"))?;
+
+        Ok(Self {
+            start: Position::parse(input, start)?,
+            end: Position::parse(input, end)?,
+        })
+    }
+
+    fn to_cells(&self) -> Box<dyn Iterator<Item = Position>> {
+        let mut current = self.start;
+        let mut done = false;
+        let end = self.end;
+
+        let iter = std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            if current == end {
+                done = true;
+            }
+
+            let next = current.next_towards(&end);
+
+            let last = current;
+            current = next;
+
+            Some(last)
+        });
+
+        Box::new(iter)
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    fn is_non_diagonal(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+}
+
+fn parse_input(s: &str) -> Result<Vec<Line>, ParseError> {
+    s.lines().map(|line| Line::parse(s, line)).collect()
+}
+
+fn get_covered_counts<'a, T>(lines: T) -> HashMap<Position, usize>
+where
+    T: Iterator<Item = &'a Line>,
+{
+    lines.flat_map(Line::to_cells).counts()
+}
+
+pub struct HydrothermalVents;
+
+impl Solution for HydrothermalVents {
+    const DAY: u8 = 5;
+    const NAME: &'static str = "hydrothermal-vents";
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let lines = input.iter().filter(|l| l.is_non_diagonal());
+        let covered_counts = get_covered_counts(lines);
+
+        Ok(covered_counts
+            .iter()
+            .filter(|(_key, value)| **value >= 2)
+            .count())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let covered_counts = get_covered_counts(input.iter());
+
+        Ok(covered_counts
+            .iter()
+            .filter(|(_key, value)| **value >= 2)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_straight() {
+        let l = Line {
+            start: Position { x: 0, y: 0 },
+            end: Position { x: 0, y: 5 },
+        };
+
+        assert_eq!(
+            l.to_cells().collect::<Vec<_>>(),
+            vec![
+                Position { x: 0, y: 0 },
+                Position { x: 0, y: 1 },
+                Position { x: 0, y: 2 },
+                Position { x: 0, y: 3 },
+                Position { x: 0, y: 4 },
+                Position { x: 0, y: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_diagnol() {
+        let l = Line {
+            start: Position { x: 0, y: 0 },
+            end: Position { x: 5, y: 5 },
+        };
+
+        assert_eq!(
+            l.to_cells().collect::<Vec<_>>(),
+            vec![
+                Position { x: 0, y: 0 },
+                Position { x: 1, y: 1 },
+                Position { x: 2, y: 2 },
+                Position { x: 3, y: 3 },
+                Position { x: 4, y: 4 },
+                Position { x: 5, y: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("0,9 -> 5,9\n8,0 => 0,8").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse_input("0,9 -> 5,x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 10, "x"));
+    }
+}
@@ -0,0 +1,20 @@
+//! Random lanternfish schools, shaped like the real puzzle input
+
+use itertools::Itertools;
+use rand::Rng;
+
+/// About as many fish as a real input
+pub const DEFAULT_SIZE: usize = 300;
+
+/// The timers of `size` fish, which start between 1 and 5 days like in the puzzle
+pub fn timers<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<u8> {
+    (0..size).map(|_| rng.gen_range(1..=5)).collect()
+}
+
+pub fn render(timers: &[u8]) -> String {
+    format!("{}\n", timers.iter().join(","))
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&timers(rng, size))
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use aoc_common::{parse::parse_at, ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+// This represents the number of snakes in each 'lifecycle'
+pub type Swarm = [usize; 9];
+
+fn parse_input(input: &str) -> Result<Swarm, ParseError> {
+    let mut swarm: Swarm = [0; 9];
+
+    for timer in input.trim().split(',') {
+        let f: usize = parse_at(input, timer, "a timer")?;
+        let count = swarm.get_mut(f).ok_or_else(|| {
+            ParseError::at(
+                input,
+                timer,
+                format!("timers run from 0 to 8 but this one is {}", f),
+            )
+        })?;
+
+        *count += 1;
+    }
+
+    Ok(swarm)
+}
+
+fn simulate_swarm(swarm: &mut Swarm) {
+    let zero = swarm[0];
+
+    for i in 0..8 {
+        swarm[i] = swarm[i + 1];
+    }
+
+    swarm[8] = zero;
+    swarm[6] += zero;
+}
+
+fn alive_after_days(mut swarm: Swarm, days: usize) -> usize {
+    for _ in 0..days {
+        simulate_swarm(&mut swarm);
+    }
+
+    swarm.iter().sum()
+}
+
+pub struct Lanternfish;
+
+impl Solution for Lanternfish {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "lanternfish";
+
+    type Input = Swarm;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        Ok(alive_after_days(*input, 80))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        Ok(alive_after_days(*input, 256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Lanternfish::solve_part1(fixtures::SAMPLE).unwrap(), 5934);
+        assert_eq!(Lanternfish::solve_part1(fixtures::MY).unwrap(), 352151);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Lanternfish::solve_part2(fixtures::SAMPLE).unwrap(),
+            26984457539
+        );
+        assert_eq!(
+            Lanternfish::solve_part2(fixtures::MY).unwrap(),
+            1601616884019
+        );
+    }
+
+    #[test]
+    fn test_timer_out_of_range() {
+        let err = parse_input("3,4,9,1\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "9"));
+        assert_eq!(err.message, "timers run from 0 to 8 but this one is 9");
+    }
+}
@@ -0,0 +1,33 @@
+//! Random crab swarms, shaped like the real puzzle input
+
+use itertools::Itertools;
+use rand::Rng;
+
+/// About as many crabs as a real input
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// How far apart `size` crabs can be while the fuel for part 2 still fits in a `u32`, up to the
+/// real input's 2000
+pub fn spread(size: usize) -> u32 {
+    let budget = 2 * u32::MAX as u64 / size.max(1) as u64;
+    let fits = (budget as f64).sqrt() as u64;
+
+    fits.saturating_sub(1).min(2000) as u32
+}
+
+/// The positions of `size` crabs, clustered towards the start like the real input
+pub fn positions<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<u32> {
+    let spread = spread(size);
+
+    (0..size)
+        .map(|_| rng.gen_range(0..=spread).min(rng.gen_range(0..=spread)))
+        .collect()
+}
+
+pub fn render(positions: &[u32]) -> String {
+    format!("{}\n", positions.iter().join(","))
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&positions(rng, size))
+}
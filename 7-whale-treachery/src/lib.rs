@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::parse_at, ParseError, Solution};
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+fn parse_input(s: &str) -> Result<Vec<u32>, ParseError> {
+    s.trim()
+        .split(',')
+        .map(|n| parse_at(s, n, "a crab position"))
+        .collect()
+}
+
+fn addition_up_to(n: u32) -> Option<u32> {
+    let n = u64::from(n);
+
+    (n * (n + 1) / 2).try_into().ok()
+}
+
+/// The fuel for every crab to reach `index`, or `None` if it doesn't fit in a `u32`
+fn sum_of_additions_diff(nums: &[u32], index: u32) -> Option<u32> {
+    let mut diff: u32 = 0;
+
+    for n in nums {
+        diff = diff.checked_add(addition_up_to(n.abs_diff(index))?)?;
+    }
+
+    Some(diff)
+}
+
+fn sum_of_diff(nums: &[u32], index: u32) -> Option<u32> {
+    let mut diff: u32 = 0;
+
+    for n in nums {
+        diff = diff.checked_add(n.abs_diff(index))?;
+    }
+
+    Some(diff)
+}
+
+/// The least fuel over the meeting points `candidates`
+fn cheapest(
+    nums: &[u32],
+    candidates: impl Iterator<Item = u32>,
+    fuel: fn(&[u32], u32) -> Option<u32>,
+) -> Result<u32> {
+    candidates
+        .filter_map(|i| fuel(nums, i))
+        .min()
+        .ok_or_else(|| anyhow!("Every meeting point needs more fuel than fits in a u32"))
+}
+
+/// Tries every meeting point from 0 to the furthest crab, slow but obviously right
+fn brute_force(nums: &[u32], fuel: fn(&[u32], u32) -> Option<u32>) -> Result<u32> {
+    let furthest = nums.iter().max().ok_or_else(|| anyhow!("No crabs"))?;
+
+    cheapest(nums, 0..=*furthest, fuel)
+}
+
+pub fn brute_force_part1(nums: &[u32]) -> Result<u32> {
+    brute_force(nums, sum_of_diff)
+}
+
+pub fn brute_force_part2(nums: &[u32]) -> Result<u32> {
+    brute_force(nums, sum_of_additions_diff)
+}
+
+pub struct WhaleTreachery;
+
+impl Solution for WhaleTreachery {
+    const DAY: u8 = 7;
+    const NAME: &'static str = "whale-treachery";
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// The total distance is lowest at the median
+    fn part1(nums: &Self::Input) -> Result<Self::Part1> {
+        let mut sorted = nums.clone();
+        sorted.sort_unstable();
+        let median = sorted
+            .get(sorted.len() / 2)
+            .ok_or_else(|| anyhow!("No crabs"))?;
+
+        cheapest(nums, std::iter::once(*median), sum_of_diff)
+    }
+
+    /// The fuel grows with the square of the distance, which puts the cheapest point within a
+    /// step or so of the mean
+    fn part2(nums: &Self::Input) -> Result<Self::Part2> {
+        let total: u64 = nums.iter().map(|&n| u64::from(n)).sum();
+        let mean = total
+            .checked_div(nums.len() as u64)
+            .ok_or_else(|| anyhow!("No crabs"))? as u32;
+
+        cheapest(
+            nums,
+            mean.saturating_sub(1)..=mean.saturating_add(2),
+            sum_of_additions_diff,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::property::{generated, same_answers};
+    use itertools::Itertools;
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(WhaleTreachery::solve_part1(fixtures::SAMPLE).unwrap(), 37);
+        assert_eq!(WhaleTreachery::solve_part2(fixtures::SAMPLE).unwrap(), 168);
+    }
+
+    #[test]
+    fn test_meeting_past_the_crab_count() {
+        assert_eq!(brute_force_part1(&[5]).unwrap(), 0);
+        assert_eq!(WhaleTreachery::part1(&vec![5]).unwrap(), 0);
+        assert_eq!(WhaleTreachery::part2(&vec![9, 9]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_too_much_fuel() {
+        assert!(WhaleTreachery::part1(&vec![0, 0, u32::MAX, u32::MAX]).is_err());
+        assert!(WhaleTreachery::part2(&vec![0, 200_000]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_fast_matches_brute_force(input in generated(generate::input, 1..100)) {
+            same_answers::<WhaleTreachery, _>(&input, |n| brute_force_part1(n), WhaleTreachery::part1)?;
+            same_answers::<WhaleTreachery, _>(&input, |n| brute_force_part2(n), WhaleTreachery::part2)?;
+        }
+
+        #[test]
+        fn test_fast_matches_brute_force_anywhere(nums in vec(0u32..300, 1..40)) {
+            let input = nums.iter().join(",");
+
+            same_answers::<WhaleTreachery, _>(&input, |n| brute_force_part1(n), WhaleTreachery::part1)?;
+            same_answers::<WhaleTreachery, _>(&input, |n| brute_force_part2(n), WhaleTreachery::part2)?;
+        }
+    }
+}
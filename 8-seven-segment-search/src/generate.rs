@@ -0,0 +1,63 @@
+//! Random scrambled displays, shaped like the real puzzle input
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+
+use super::{CHARS, MAPPINGS};
+
+/// About as many displays as a real input
+pub const DEFAULT_SIZE: usize = 200;
+
+/// One display's ten unique signal patterns and its four output digits, all wired up the same
+/// scrambled way
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub patterns: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+fn scramble<R: Rng + ?Sized>(rng: &mut R, wiring: &[char], digit: usize) -> String {
+    let mut segments = MAPPINGS[digit]
+        .chars()
+        .map(|c| wiring[c as usize - 'a' as usize])
+        .collect_vec();
+    segments.shuffle(rng);
+
+    segments.into_iter().collect()
+}
+
+pub fn entries<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<Entry> {
+    (0..size)
+        .map(|_| {
+            let mut wiring = CHARS.to_vec();
+            wiring.shuffle(rng);
+
+            let mut digits = (0..10).collect_vec();
+            digits.shuffle(rng);
+
+            Entry {
+                patterns: digits
+                    .into_iter()
+                    .map(|d| scramble(rng, &wiring, d))
+                    .collect(),
+                outputs: (0..4)
+                    .map(|_| {
+                        let digit = rng.gen_range(0..10);
+                        scramble(rng, &wiring, digit)
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn render(entries: &[Entry]) -> String {
+    entries
+        .iter()
+        .map(|e| format!("{} | {}\n", e.patterns.join(" "), e.outputs.join(" ")))
+        .collect()
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&entries(rng, size))
+}
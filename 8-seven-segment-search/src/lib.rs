@@ -0,0 +1,202 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::char_at, ParseError, Solution};
+use itertools::Itertools;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+const MAPPINGS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+const CHARS: [char; 7] = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+
+#[derive(Debug, Clone)]
+pub struct ValidArrangements(Vec<String>);
+
+struct Puzzle {
+    order: [Option<char>; 7],
+    valid_arrangements: ValidArrangements,
+    digits_signals: Vec<String>,
+}
+
+impl Puzzle {
+    /// The displayed number, or `None` if the patterns aren't a consistent wiring of the digits
+    fn solve(&mut self) -> Option<u64> {
+        let one_pattern = self.valid_arrangements.0.iter().find(|s| s.len() == 2)?;
+        let four_pattern = self.valid_arrangements.0.iter().find(|s| s.len() == 4)?;
+        let seven_pattern = self.valid_arrangements.0.iter().find(|s| s.len() == 3)?;
+
+        let character_counts = self
+            .valid_arrangements
+            .0
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<String>()
+            .chars()
+            .counts();
+
+        // Determine the top segment
+        self.order[0] = Some(
+            seven_pattern
+                .chars()
+                .find(|x| !one_pattern.contains(&x.to_string()))?,
+        );
+
+        self.order[4] = Some(*character_counts.iter().find(|(_, v)| **v == 4)?.0);
+        self.order[5] = Some(*character_counts.iter().find(|(_, v)| **v == 9)?.0);
+        self.order[1] = Some(*character_counts.iter().find(|(_, v)| **v == 6)?.0);
+
+        let a_or_c = character_counts
+            .iter()
+            .filter(|(_, v)| **v == 8)
+            .map(|(k, _)| k.to_string())
+            .collect::<String>();
+
+        self.order[2] = Some(a_or_c.chars().find(|x| self.order[0] != Some(*x))?);
+
+        let d_or_g = character_counts
+            .iter()
+            .filter(|(_, v)| **v == 7)
+            .map(|(k, _)| k.to_string())
+            .collect::<String>();
+
+        self.order[3] = Some(
+            d_or_g
+                .chars()
+                .find(|x| four_pattern.contains(&x.to_string()))?,
+        );
+
+        self.order[6] = Some(
+            d_or_g
+                .chars()
+                .find(|x| !four_pattern.contains(&x.to_string()))?,
+        );
+
+        let what_was_supposed_to_display_digits = self
+            .digits_signals
+            .iter()
+            .map(|s| {
+                let corrected_sequence = s
+                    .chars()
+                    .map(|c| {
+                        let index = self.order.iter().position(|x| x == &Some(c))?;
+                        Some(CHARS[index])
+                    })
+                    .collect::<Option<Vec<char>>>()?
+                    .into_iter()
+                    .sorted()
+                    .collect::<String>();
+
+                MAPPINGS.iter().position(|x| x == &corrected_sequence)
+            })
+            .collect::<Option<Vec<usize>>>()?;
+
+        Some(
+            what_was_supposed_to_display_digits
+                .into_iter()
+                .fold(0, |number, digit| number * 10 + digit as u64),
+        )
+    }
+}
+
+/// Parses the `count` patterns in the `half` slice of `input`, each made up of segments a to g
+fn parse_patterns(input: &str, half: &str, count: usize) -> Result<Vec<String>, ParseError> {
+    let patterns = half.split_whitespace().collect_vec();
+
+    if patterns.len() != count {
+        return Err(ParseError::at(
+            input,
+            half.trim(),
+            format!("expected {} patterns, found {}", count, patterns.len()),
+        ));
+    }
+
+    for pattern in &patterns {
+        if let Some((i, c)) = pattern.char_indices().find(|(_, c)| !CHARS.contains(c)) {
+            return Err(ParseError::at(
+                input,
+                char_at(pattern, i),
+                format!("`{}` isn't a segment, expected a letter from a to g", c),
+            ));
+        }
+    }
+
+    Ok(patterns.into_iter().map(|x| x.to_owned()).collect())
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(ValidArrangements, Vec<String>), ParseError> {
+    let (left, right) = line.split_once('|').ok_or_else(|| {
+        ParseError::at(
+            input,
+            line,
+            "expected signal patterns and output digits separated by `|`",
+        )
+    })?;
+
+    Ok((
+        ValidArrangements(parse_patterns(input, left, 10)?),
+        parse_patterns(input, right, 4)?,
+    ))
+}
+
+fn parse_str(s: &str) -> Result<Vec<(ValidArrangements, Vec<String>)>, ParseError> {
+    s.trim().lines().map(|line| parse_line(s, line)).collect()
+}
+
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    const DAY: u8 = 8;
+    const NAME: &'static str = "seven-segment-search";
+
+    type Input = Vec<(ValidArrangements, Vec<String>)>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input
+            .iter()
+            .map(|(_, right)| {
+                right
+                    .iter()
+                    .filter(|x| {
+                        let length = x.len();
+
+                        length == 2 || length == 3 || length == 4 || length == 7
+                    })
+                    .count()
+            })
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, (valid_arrangements, right))| {
+                let mut puzzle = Puzzle {
+                    order: [None, None, None, None, None, None, None],
+                    valid_arrangements: valid_arrangements.clone(),
+                    digits_signals: right.clone(),
+                };
+
+                puzzle.solve().ok_or_else(|| {
+                    anyhow!("Entry {} isn't a consistent wiring of the digits", i + 1)
+                })
+            })
+            .sum()
+    }
+}
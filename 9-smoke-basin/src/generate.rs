@@ -0,0 +1,40 @@
+//! Random height maps, shaped like the real puzzle input
+
+use grid::Grid;
+use rand::Rng;
+
+use super::low_points;
+
+/// About as wide and tall as a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` height map with at least the three basins part 2 needs, so `size` has to
+/// be at least 3
+///
+/// Over half the map is 9s, which keeps basins walled off and about as small as the real ones
+pub fn heights<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Grid<u8> {
+    assert!(size >= 3, "A {0}x{0} map can't hold three basins", size);
+
+    loop {
+        let mut map = Grid::new(size, size, 0);
+        for height in map.values_mut() {
+            *height = if rng.gen_bool(0.55) {
+                9
+            } else {
+                rng.gen_range(0..9)
+            };
+        }
+
+        if low_points(&map).len() >= 3 {
+            return map;
+        }
+    }
+}
+
+pub fn render(heights: &Grid<u8>) -> String {
+    format!("{}\n", heights)
+}
+
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    render(&heights(rng, size.max(3)))
+}
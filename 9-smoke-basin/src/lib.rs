@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, Solution};
+use grid::{Grid, Pos};
+use itertools::Itertools;
+
+/// Where this day's bundled `.input` files live
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The bundled inputs, embedded at compile time for tests
+pub mod fixtures {
+    pub const SAMPLE: &str = include_str!("sample.input");
+    pub const MY: &str = include_str!("my.input");
+}
+
+pub mod generate;
+
+fn is_low_point(board: &Grid<u8>, pos: Pos) -> bool {
+    board
+        .neighbours4(pos)
+        .all(|neighbour| board[pos] < board[neighbour])
+}
+
+fn low_points(board: &Grid<u8>) -> Vec<Pos> {
+    board
+        .positions()
+        .filter(|pos| is_low_point(board, *pos))
+        .collect_vec()
+}
+
+fn find_basin_size(board: &Grid<u8>, low_point: Pos) -> u32 {
+    let mut visited = Grid::new(board.width(), board.height(), false);
+    let mut queue = vec![low_point];
+    let mut size = 0;
+
+    while let Some(pos) = queue.pop() {
+        if visited[pos] {
+            continue;
+        }
+        visited[pos] = true;
+
+        if board[pos] != 9 {
+            size += 1;
+            queue.extend(board.neighbours4(pos));
+        }
+    }
+
+    size
+}
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+    const NAME: &'static str = "smoke-basin";
+
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(board: &Self::Input) -> Result<Self::Part1> {
+        Ok(low_points(board)
+            .into_iter()
+            .map(|pos| board[pos] as u32 + 1)
+            .sum())
+    }
+
+    fn part2(board: &Self::Input) -> Result<Self::Part2> {
+        let mut basin_sizes = low_points(board)
+            .into_iter()
+            .map(|pos| find_basin_size(board, pos))
+            .collect_vec();
+
+        basin_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        match basin_sizes[..] {
+            [a, b, c, ..] => a
+                .checked_mul(b)
+                .and_then(|ab| ab.checked_mul(c))
+                .ok_or_else(|| anyhow!("The basins are too big to multiply in a u32")),
+            _ => Err(anyhow!(
+                "Only found {} basins, part 2 needs 3",
+                basin_sizes.len()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(SmokeBasin::solve_part1(fixtures::SAMPLE).unwrap(), 15);
+        assert_eq!(SmokeBasin::solve_part1(fixtures::MY).unwrap(), 465);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(SmokeBasin::solve_part2(fixtures::SAMPLE).unwrap(), 1134);
+        assert_eq!(SmokeBasin::solve_part2(fixtures::MY).unwrap(), 1269555);
+    }
+
+    #[test]
+    fn test_too_few_basins() {
+        let err = SmokeBasin::solve_part2("19\n99").unwrap_err();
+
+        assert_eq!(err.to_string(), "Only found 1 basins, part 2 needs 3");
+    }
+}
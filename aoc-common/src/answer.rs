@@ -0,0 +1,60 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+/// A solved part, classified so it can be reported as structured data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "answer_type", content = "answer", rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Answers drawn over several lines, like day 13's folded paper
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    pub fn new(answer: impl Display) -> Self {
+        let s = answer.to_string();
+
+        if let Ok(n) = s.parse() {
+            Answer::Integer(n)
+        } else if s.contains('\n') {
+            Answer::Lines(s.lines().map(str::to_owned).collect())
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Answer::new(42u64), Answer::Integer(42));
+        assert_eq!(Answer::new(-3), Answer::Integer(-3));
+        assert_eq!(Answer::new("GJZGLUPJ"), Answer::Text("GJZGLUPJ".to_owned()));
+        assert_eq!(
+            Answer::new("# \n.#"),
+            Answer::Lines(vec!["# ".to_owned(), ".#".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for answer in ["42", "GJZGLUPJ", "# \n.#"] {
+            assert_eq!(Answer::new(answer).to_string(), answer);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Result};
+
+/// Environment variable pointing at a directory of inputs laid out as `<day>/<name>.input`
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// `<name>.input` inside `dir`, ie `my` or `sample`
+    Named {
+        dir: PathBuf,
+        name: String,
+    },
+}
+
+impl Source {
+    /// Identifies the input in machine readable output, ie `my`, `stdin` or a path
+    pub fn name(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "stdin".to_owned(),
+            Source::Named { name, .. } => name.clone(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "stdin".to_owned(),
+            Source::Named { name, .. } => {
+                let mut chars = name.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut s = String::new();
+                io::stdin()
+                    .read_to_string(&mut s)
+                    .context("Couldn't read input from stdin")?;
+
+                Ok(s)
+            }
+            Source::Named { dir, name } => {
+                let path = dir.join(format!("{}.input", name));
+
+                if path.is_file() {
+                    return read_file(&path);
+                }
+
+                if !dir.is_dir() {
+                    return Err(anyhow!(
+                        "No input named `{}`, the inputs directory {} doesn't exist",
+                        name,
+                        dir.display()
+                    ));
+                }
+
+                let available = available_names(dir);
+                if available.is_empty() {
+                    Err(anyhow!(
+                        "No input named `{}`, {} doesn't contain any .input files",
+                        name,
+                        dir.display()
+                    ))
+                } else {
+                    Err(anyhow!(
+                        "No input named `{}` in {}, available inputs are: {}",
+                        name,
+                        dir.display(),
+                        available.join(", ")
+                    ))
+                }
+            }
+        }
+    }
+
+    pub fn load(&self) -> Result<Input> {
+        Ok(Input {
+            label: self.label(),
+            text: self.read()?,
+        })
+    }
+}
+
+/// `-` is stdin, anything else is a path to a file
+impl FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub label: String,
+    pub text: String,
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Couldn't read input file {}", path.display()))
+}
+
+/// The names of the `.input` files in `dir`, sorted
+pub fn available_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+
+            if path.extension()? == "input" {
+                Some(path.file_stem()?.to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+
+    names
+}
+
+/// The directory holding a day's named inputs
+///
+/// With an inputs dir this is `<inputs_dir>/<day>`, otherwise it falls back to the inputs bundled
+/// in the day's crate
+pub fn day_dir(inputs_dir: Option<&Path>, day: u8, bundled: &str) -> PathBuf {
+    match inputs_dir {
+        Some(dir) => dir.join(day.to_string()),
+        None => PathBuf::from(bundled),
+    }
+}
+
+/// The inputs dir configured through [`INPUTS_DIR_VAR`], if any
+pub fn inputs_dir_from_env() -> Option<PathBuf> {
+    env::var_os(INPUTS_DIR_VAR).map(PathBuf::from)
+}
+
+/// Loads the inputs passed on the command line, or the day's `defaults` when there are none
+pub fn from_args(day: u8, bundled: &str, defaults: &[&str]) -> Result<Vec<Input>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let sources: Vec<Source> = if args.is_empty() {
+        let dir = day_dir(inputs_dir_from_env().as_deref(), day, bundled);
+
+        defaults
+            .iter()
+            .map(|name| Source::Named {
+                dir: dir.clone(),
+                name: name.to_string(),
+            })
+            .collect()
+    } else {
+        args.iter()
+            .map(|arg| arg.parse().expect("parsing a Source is infallible"))
+            .collect()
+    };
+
+    sources.iter().map(Source::load).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!(
+            "some/where.input".parse(),
+            Ok(Source::File("some/where.input".into()))
+        );
+    }
+
+    #[test]
+    fn test_label() {
+        let named = Source::Named {
+            dir: "inputs/7".into(),
+            name: "sample".to_owned(),
+        };
+
+        assert_eq!(named.label(), "Sample");
+        assert_eq!(Source::Stdin.label(), "stdin");
+    }
+
+    #[test]
+    fn test_day_dir() {
+        assert_eq!(
+            day_dir(Some(Path::new("inputs")), 7, "7-whale-treachery/src"),
+            PathBuf::from("inputs/7")
+        );
+        assert_eq!(
+            day_dir(None, 7, "7-whale-treachery/src"),
+            PathBuf::from("7-whale-treachery/src")
+        );
+    }
+
+    #[test]
+    fn test_missing_named_input() {
+        let source = Source::Named {
+            dir: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src"),
+            name: "nope".to_owned(),
+        };
+
+        let err = source.read().unwrap_err().to_string();
+        assert!(err.starts_with("No input named `nope`"), "{}", err);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = Source::File("does/not/exist.input".into())
+            .read()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Couldn't read input file does/not/exist.input"
+        );
+    }
+}
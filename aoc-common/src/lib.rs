@@ -0,0 +1,10 @@
+pub mod answer;
+pub mod input;
+pub mod parse;
+#[cfg(feature = "proptest")]
+pub mod property;
+pub mod solution;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use solution::{DynSolution, Part, Solution};
@@ -0,0 +1,175 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Why and where an input couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// Counted from 1
+    pub line: usize,
+    /// Counted from 1, in characters rather than bytes
+    pub column: usize,
+    /// The text that couldn't be parsed, empty when something was missing
+    pub text: String,
+    /// The whole of the offending line, to show the error in context
+    pub line_text: String,
+}
+
+impl ParseError {
+    /// An error blaming `span`, which has to be a slice of `input` like the ones `lines`, `split`
+    /// and `trim` hand out
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(
+            offset <= input.len() && offset + span.len() <= input.len(),
+            "span `{}` isn't part of the input",
+            span
+        );
+        let start = offset.min(input.len());
+
+        let before = &input[..start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+
+        ParseError {
+            message: message.into(),
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            text: span.to_owned(),
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    /// An error for input that ran out before something required showed up
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// An error pointing just past the end of `span`, for something missing after it
+    pub fn after(input: &str, span: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &span[span.len()..], message)
+    }
+
+    /// Moves the error down by `lines`, for input read in pieces where each piece only knows its
+    /// own line numbers
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |first| first.chars().count())
+            .max(1);
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        write!(
+            f,
+            "{:gutter$} | {:indent$}{}",
+            "",
+            "",
+            "^".repeat(width),
+            gutter = gutter,
+            indent = self.column - 1
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `span` with [`FromStr`], blaming `span` when it isn't valid
+///
+/// `what` describes the value in the error, ie "a depth"
+pub fn parse_at<T>(input: &str, span: &str, what: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    span.parse().map_err(|e| {
+        ParseError::at(
+            input,
+            span,
+            format!("couldn't parse `{}` as {}: {}", span, what, e),
+        )
+    })
+}
+
+/// The slice of `s` holding the character that starts at byte `index`
+pub fn char_at(s: &str, index: usize) -> &str {
+    let len = s[index..].chars().next().map_or(0, char::len_utf8);
+
+    &s[index..index + len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locates_span() {
+        let input = "forward 5\nup x\ndown 2";
+        let line = input.lines().nth(1).unwrap();
+        let span = line.split(' ').nth(1).unwrap();
+
+        let err = ParseError::at(input, span, "expected a number");
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.text, "x");
+        assert_eq!(err.line_text, "up x");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a number\n2 | up x\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        let input = "██x";
+        let err = ParseError::at(input, char_at(input, "██".len()), "not a block");
+
+        assert_eq!(err.column, 3);
+        assert_eq!(err.text, "x");
+    }
+
+    #[test]
+    fn test_missing_text() {
+        let input = "1,2\n\nfold along";
+        let line = input.lines().last().unwrap();
+
+        let err = ParseError::after(input, line, "missing the fold");
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 11, ""));
+
+        let err = ParseError::end_of_input("1,2\n", "missing the folds");
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.line_text, "");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "1,22,x3";
+        let spans: Vec<&str> = input.split(',').collect();
+
+        assert_eq!(parse_at::<u32>(input, spans[1], "a number"), Ok(22));
+
+        let err = parse_at::<u32>(input, spans[2], "a number").unwrap_err();
+        assert_eq!(
+            err.message,
+            "couldn't parse `x3` as a number: invalid digit found in string"
+        );
+        assert_eq!(err.column, 6);
+        assert!(err.to_string().ends_with("|      ^^"));
+    }
+}
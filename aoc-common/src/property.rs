@@ -0,0 +1,128 @@
+use std::{fmt::Debug, ops::Range};
+
+use anyhow::Result;
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestCaseResult},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::Solution;
+
+/// Inputs made by one of the days' `generate::input` functions, with a size from `sizes`
+///
+/// Failing inputs shrink towards smaller sizes and lower seeds, but every size makes a different
+/// input so this stops at the first smaller one that passes. Strategies over a day's own values
+/// shrink much further, so it's worth having one of those too
+pub fn generated<F>(generate: F, sizes: Range<usize>) -> impl Strategy<Value = String>
+where
+    F: Fn(&mut StdRng, usize) -> String,
+{
+    (sizes, any::<u64>())
+        .prop_map(move |(size, seed)| generate(&mut StdRng::seed_from_u64(seed), size))
+}
+
+/// Checks `alternative` gets the same answer as the `reference` solver for `input`
+///
+/// An input that doesn't parse is rejected rather than failing, since it says nothing about
+/// either solver
+pub fn same_answers<S, T>(
+    input: &str,
+    reference: impl Fn(&S::Input) -> Result<T>,
+    alternative: impl Fn(&S::Input) -> Result<T>,
+) -> TestCaseResult
+where
+    S: Solution,
+    T: PartialEq + Debug,
+{
+    let parsed = S::parse(input).map_err(|e| TestCaseError::reject(e.to_string()))?;
+
+    let expected = reference(&parsed)
+        .map_err(|e| TestCaseError::fail(format!("The reference solver failed: {}", e)))?;
+    let actual = alternative(&parsed)
+        .map_err(|e| TestCaseError::fail(format!("The alternative solver failed: {}", e)))?;
+
+    prop_assert_eq!(expected, actual, "Solvers disagree on\n{}", input);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::{parse::parse_at, ParseError};
+
+    struct Max;
+
+    impl Solution for Max {
+        const DAY: u8 = 0;
+        const NAME: &'static str = "max";
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|n| parse_at(input, n, "a number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().copied().max().unwrap_or(0))
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2> {
+            // Wrong as soon as there are two numbers
+            Ok(input.first().copied().unwrap_or(0))
+        }
+    }
+
+    fn numbers(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.gen_range(0..100)))
+            .collect()
+    }
+
+    #[test]
+    fn test_same_answers() {
+        let sorted_max = |input: &Vec<u32>| {
+            let mut sorted = input.clone();
+            sorted.sort_unstable();
+            Ok(sorted.last().copied().unwrap_or(0))
+        };
+
+        assert!(same_answers::<Max, _>("3\n9\n4", Max::part1, sorted_max).is_ok());
+        assert!(matches!(
+            same_answers::<Max, _>("3\n9\n4", Max::part1, Max::part2),
+            Err(TestCaseError::Fail(_))
+        ));
+        assert!(matches!(
+            same_answers::<Max, _>("3\nnine", Max::part1, Max::part2),
+            Err(TestCaseError::Reject(_))
+        ));
+    }
+
+    #[test]
+    fn test_failures_shrink() {
+        let mut runner = proptest::test_runner::TestRunner::deterministic();
+        let result = runner.run(&generated(numbers, 1..50), |input| {
+            same_answers::<Max, _>(&input, Max::part1, Max::part2)
+        });
+
+        match result {
+            Err(proptest::test_runner::TestError::Fail(_, input)) => {
+                assert_eq!(
+                    input.lines().count(),
+                    2,
+                    "Didn't shrink to 2 numbers:\n{}",
+                    input
+                )
+            }
+            other => panic!("Expected a failure, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    fmt,
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Serialize, Serializer};
+
+use crate::{answer::Answer, input::Input, parse::ParseError};
+
+/// A day's puzzle, split into parsing the input once and solving each part from the parsed input
+pub trait Solution {
+    const DAY: u8;
+    const NAME: &'static str;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Malformed input is reported with where it went wrong rather than panicking
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+
+    fn solve_part1(input: &str) -> Result<Self::Part1> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solve_part2(input: &str) -> Result<Self::Part2> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("There is no part {}, only 1 and 2", s)),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+}
+
+/// The answers from running a solution over one input, along with how long each step took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Object safe view of a [`Solution`], so every day can live in one list
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn name(&self) -> &'static str;
+
+    fn solve(&self, input: &str, part: Part) -> Result<String>;
+
+    /// Parses `input` once and solves each of `parts` from it, timing both steps
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run>;
+}
+
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let value = f()?;
+
+    Ok((value, start.elapsed()))
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<String> {
+        match part {
+            Part::One => S::solve_part1(input).map(|ans| ans.to_string()),
+            Part::Two => S::solve_part2(input).map(|ans| ans.to_string()),
+        }
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        let (parsed, parse_time) = timed(|| S::parse(input).map_err(anyhow::Error::from))?;
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let (answer, solve_time) = match part {
+                    Part::One => timed(|| S::part1(&parsed).map(Answer::new))?,
+                    Part::Two => timed(|| S::part2(&parsed).map(Answer::new))?,
+                };
+
+                Ok(PartRun {
+                    part,
+                    answer,
+                    solve_time,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Run { parse_time, parts })
+    }
+}
+
+/// Prints the answers to both parts for each input, the way every day's binary reports them
+pub fn print_answers<S: Solution>(inputs: &[Input]) -> Result<()> {
+    let parsed = inputs
+        .iter()
+        .map(|input| Ok((&input.label, S::parse(&input.text)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    println!("Part 1");
+    for (label, input) in &parsed {
+        print_answer(label, S::part1(input)?);
+    }
+
+    println!();
+    println!("Part 2");
+    for (label, input) in &parsed {
+        print_answer(label, S::part2(input)?);
+    }
+
+    Ok(())
+}
+
+fn print_answer(label: &str, answer: impl Display) {
+    let answer = answer.to_string();
+
+    if answer.contains('\n') {
+        println!("{}:\n{}", label, answer);
+    } else {
+        println!("{}: {}", label, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_at;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const NAME: &'static str = "sum";
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split(',')
+                .map(|n| parse_at(input, n, "a number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2> {
+            Ok(input
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Sum::solve_part1("1,2,3").unwrap(), 6);
+        assert!(Sum::solve_part1("1,two").is_err());
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Sum;
+
+        assert_eq!(solution.day(), 0);
+        assert_eq!(solution.solve("1,2", Part::One).unwrap(), "3");
+        assert_eq!(solution.solve("1,2", Part::Two).unwrap(), "1\n2");
+    }
+
+    #[test]
+    fn test_run() {
+        let run = Sum.run("1,2", &[Part::Two, Part::One]).unwrap();
+
+        assert_eq!(
+            run.parts
+                .iter()
+                .map(|p| (p.part, &p.answer))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Part::Two,
+                    &Answer::Lines(vec!["1".to_owned(), "2".to_owned()])
+                ),
+                (Part::One, &Answer::Integer(3)),
+            ]
+        );
+        assert!(Sum.run("1,x", &Part::BOTH).is_err());
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! Times parsing and both parts of every day on its `sample` and `my` inputs
+//!
+//! Criterion keeps each run's results in `target/criterion` and reports how much every benchmark
+//! changed since the previous run, see the README for comparing against a saved baseline.
+
+use aoc_common::{
+    input::{self, Source},
+    Solution,
+};
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const INPUTS: [&str; 2] = ["sample", "my"];
+
+fn bench_day<S: Solution>(c: &mut Criterion, bundled: &str) {
+    let dir = input::day_dir(input::inputs_dir_from_env().as_deref(), S::DAY, bundled);
+    let mut group = c.benchmark_group(format!("day{:02}-{}", S::DAY, S::NAME));
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3));
+
+    for name in INPUTS {
+        let source = Source::Named {
+            dir: dir.clone(),
+            name: name.to_owned(),
+        };
+        // Not every day has both inputs, day 12 only has `my` and its own small ones
+        let text = match source.read() {
+            Ok(text) => text,
+            Err(_) => continue,
+        };
+        let parsed = S::parse(&text).expect("bundled inputs should parse");
+
+        group.bench_with_input(BenchmarkId::new("parse", name), &text, |b, text| {
+            b.iter(|| S::parse(black_box(text)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(black_box(parsed)))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(black_box(parsed)))
+        });
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<sonar_sweep::SonarSweep>(c, sonar_sweep::INPUTS_DIR);
+    bench_day::<dive::Dive>(c, dive::INPUTS_DIR);
+    bench_day::<binary_diagnostic::BinaryDiagnostic>(c, binary_diagnostic::INPUTS_DIR);
+    bench_day::<giant_squid::GiantSquid>(c, giant_squid::INPUTS_DIR);
+    bench_day::<hydrothermal_vents::HydrothermalVents>(c, hydrothermal_vents::INPUTS_DIR);
+    bench_day::<lanternfish::Lanternfish>(c, lanternfish::INPUTS_DIR);
+    bench_day::<whale_treachery::WhaleTreachery>(c, whale_treachery::INPUTS_DIR);
+    bench_day::<seven_segment_search::SevenSegmentSearch>(c, seven_segment_search::INPUTS_DIR);
+    bench_day::<smoke_basin::SmokeBasin>(c, smoke_basin::INPUTS_DIR);
+    bench_day::<syntax_scoring::SyntaxScoring>(c, syntax_scoring::INPUTS_DIR);
+    bench_day::<dumbo_octopus::DumboOctopus>(c, dumbo_octopus::INPUTS_DIR);
+    bench_day::<passage_pathing::PassagePathing>(c, passage_pathing::INPUTS_DIR);
+    bench_day::<transparent_oragami::TransparentOragami>(c, transparent_oragami::INPUTS_DIR);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
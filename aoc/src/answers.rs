@@ -0,0 +1,112 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use aoc_common::Part;
+use serde::Deserialize;
+
+/// The registry that ships with the workspace
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Expected answers keyed by day, then by input name
+///
+/// TOML keys are always strings, so the day numbers are kept as strings too
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expected>>);
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self> {
+        toml::from_str(s).context("Couldn't parse the expected answers")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read expected answers from {}", path.display()))?;
+
+        Self::parse(&s).with_context(|| format!("in {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&str> {
+        self.0.get(&day.to_string())?.get(input)?.get(part)
+    }
+
+    /// The names of the inputs with answers recorded for `day`
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.0
+            .get(&day.to_string())
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+}
+
+/// Compares answers ignoring trailing whitespace, which multi-line answers tend to pick up
+pub fn matches(expected: &str, actual: &str) -> bool {
+    fn normalize(s: &str) -> impl Iterator<Item = &str> {
+        s.trim_matches('\n').lines().map(str::trim_end)
+    }
+
+    normalize(expected).eq(normalize(actual))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[7.sample]
+part1 = "37"
+
+[13.sample]
+part2 = '''
+##
+#.'''
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(7, "sample", Part::One), Some("37"));
+        assert_eq!(answers.get(7, "sample", Part::Two), None);
+        assert_eq!(answers.get(7, "my", Part::One), None);
+        assert_eq!(answers.get(13, "sample", Part::Two), Some("##\n#."));
+        assert_eq!(answers.inputs(7).collect::<Vec<_>>(), vec!["sample"]);
+        assert_eq!(answers.inputs(8).count(), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Answers::parse("[7.sample]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("12", "12"));
+        assert!(!matches("12", "13"));
+        assert!(matches("##\n#.", "## \n#.  \n"));
+        assert!(!matches("##\n#.", "##\n.#"));
+    }
+
+    #[test]
+    fn test_bundled_answers_parse() {
+        let answers = Answers::load(Path::new(DEFAULT_PATH)).unwrap();
+
+        assert_eq!(answers.get(6, "my", Part::Two), Some("1601616884019"));
+    }
+}
@@ -0,0 +1,277 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{solution::Run, DynSolution, Part};
+use rand::rngs::StdRng;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solution: &'static dyn DynSolution,
+    /// Where the inputs bundled with the day's crate live
+    pub inputs_dir: &'static str,
+    /// Makes a random input in the day's format, see each day's `generate` module for what the
+    /// size means
+    pub generator: fn(&mut StdRng, usize) -> String,
+    /// A size for `generator` that's about as big as a real input
+    pub default_size: usize,
+}
+
+impl Day {
+    pub fn number(&self) -> u8 {
+        self.solution.day()
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.solution.name()
+    }
+
+    /// The workspace directory this day's crate lives in, ie `7-whale-treachery`
+    pub fn dir(&self) -> String {
+        format!("{}-{}", self.number(), self.name())
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<String> {
+        self.solution.solve(input, part)
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        self.solution.run(input, parts)
+    }
+
+    pub fn generate(&self, rng: &mut StdRng, size: Option<usize>) -> String {
+        (self.generator)(rng, size.unwrap_or(self.default_size))
+    }
+}
+
+pub const DAYS: [Day; 13] = [
+    Day {
+        solution: &sonar_sweep::SonarSweep,
+        inputs_dir: sonar_sweep::INPUTS_DIR,
+        generator: sonar_sweep::generate::input,
+        default_size: sonar_sweep::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &dive::Dive,
+        inputs_dir: dive::INPUTS_DIR,
+        generator: dive::generate::input,
+        default_size: dive::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &binary_diagnostic::BinaryDiagnostic,
+        inputs_dir: binary_diagnostic::INPUTS_DIR,
+        generator: binary_diagnostic::generate::input,
+        default_size: binary_diagnostic::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &giant_squid::GiantSquid,
+        inputs_dir: giant_squid::INPUTS_DIR,
+        generator: giant_squid::generate::input,
+        default_size: giant_squid::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &hydrothermal_vents::HydrothermalVents,
+        inputs_dir: hydrothermal_vents::INPUTS_DIR,
+        generator: hydrothermal_vents::generate::input,
+        default_size: hydrothermal_vents::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &lanternfish::Lanternfish,
+        inputs_dir: lanternfish::INPUTS_DIR,
+        generator: lanternfish::generate::input,
+        default_size: lanternfish::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &whale_treachery::WhaleTreachery,
+        inputs_dir: whale_treachery::INPUTS_DIR,
+        generator: whale_treachery::generate::input,
+        default_size: whale_treachery::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &seven_segment_search::SevenSegmentSearch,
+        inputs_dir: seven_segment_search::INPUTS_DIR,
+        generator: seven_segment_search::generate::input,
+        default_size: seven_segment_search::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &smoke_basin::SmokeBasin,
+        inputs_dir: smoke_basin::INPUTS_DIR,
+        generator: smoke_basin::generate::input,
+        default_size: smoke_basin::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &syntax_scoring::SyntaxScoring,
+        inputs_dir: syntax_scoring::INPUTS_DIR,
+        generator: syntax_scoring::generate::input,
+        default_size: syntax_scoring::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &dumbo_octopus::DumboOctopus,
+        inputs_dir: dumbo_octopus::INPUTS_DIR,
+        generator: dumbo_octopus::generate::input,
+        default_size: dumbo_octopus::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &passage_pathing::PassagePathing,
+        inputs_dir: passage_pathing::INPUTS_DIR,
+        generator: passage_pathing::generate::input,
+        default_size: passage_pathing::generate::DEFAULT_SIZE,
+    },
+    Day {
+        solution: &transparent_oragami::TransparentOragami,
+        inputs_dir: transparent_oragami::INPUTS_DIR,
+        generator: transparent_oragami::generate::input,
+        default_size: transparent_oragami::generate::DEFAULT_SIZE,
+    },
+];
+
+pub fn get(number: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.number() == number)
+        .ok_or_else(|| anyhow!("No solution for day {}", number))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        path::PathBuf,
+    };
+
+    use super::*;
+    use aoc_common::{
+        input::{available_names, Source},
+        ParseError,
+    };
+    use rand::{seq::SliceRandom, Rng, SeedableRng};
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(7).unwrap().dir(), "7-whale-treachery");
+        assert!(get(25).is_err());
+    }
+
+    #[test]
+    fn test_days_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number() as usize, i + 1);
+            assert!(day.inputs_dir.ends_with(&format!("{}/src", day.dir())));
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = get(6).unwrap();
+
+        assert_eq!(day.solve("3,4,3,1,2", Part::Two).unwrap(), "26984457539");
+    }
+
+    #[test]
+    fn test_malformed_inputs_are_located() {
+        let malformed = [
+            (1, "199\n2OO\n", (2, 1)),
+            (2, "forward 5\nbackward 2\n", (2, 1)),
+            (3, "10110\n10x10\n", (2, 3)),
+            (4, "7,4,9\n\n1 2 3 4 5\n6 7 8 9\n", (4, 1)),
+            (5, "0,9 -> 5,9\n8,0 -> 0,z\n", (2, 10)),
+            (6, "3,4,3,1,12\n", (1, 9)),
+            (7, "16,1,2,,0\n", (1, 8)),
+            (8, "ab | cd\n", (1, 1)),
+            (9, "2199\n39x7\n", (2, 3)),
+            (10, "[({(<(())[]>[[{[]{<()<>>\n(?]\n", (2, 2)),
+            (11, "5483\n274\n", (2, 1)),
+            (12, "start-A\nA-\n", (2, 3)),
+            (13, "6,10\n0,14\n\nfold along z=7\n", (4, 12)),
+        ];
+
+        for (n, input, location) in malformed {
+            let day = get(n).unwrap();
+            let err = day
+                .run(input, &Part::BOTH)
+                .expect_err(&format!("Day {} accepted `{}`", n, input));
+            let err = err
+                .downcast_ref::<ParseError>()
+                .unwrap_or_else(|| panic!("Day {} failed with `{}`", n, err));
+            assert_eq!((err.line, err.column), location, "Day {}: {}", n, err);
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in DAYS {
+            // The smallest sizes get bumped up to whatever the day needs to be solvable
+            for size in [1, 2, (day.default_size / 10).max(3)] {
+                for seed in 0..3 {
+                    let input = day.generate(&mut StdRng::seed_from_u64(seed), Some(size));
+
+                    if let Err(e) = day.run(&input, &Part::BOTH) {
+                        panic!(
+                            "Day {} size {} seed {}: {:#}\n{}",
+                            day.number(),
+                            size,
+                            seed,
+                            e,
+                            input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Makes a few small random edits to `input`, which mostly keeps it close enough to the real
+    /// format to get past the parser and into the solvers
+    fn mutate(rng: &mut StdRng, input: &str) -> String {
+        const CHARS: [char; 18] = [
+            '0', '1', '5', '9', 'a', 'x', 'y', 'A', ',', '-', '>', '|', '=', '(', ']', ' ', '\n',
+            '█',
+        ];
+        let mut chars: Vec<char> = input.chars().collect();
+
+        for _ in 0..rng.gen_range(1..=3) {
+            let at = rng.gen_range(0..=chars.len());
+            let end = (at + rng.gen_range(1..40)).min(chars.len());
+
+            match rng.gen_range(0..5) {
+                0 if at < chars.len() => chars[at] = *CHARS.choose(rng).unwrap(),
+                1 => chars.insert(at, *CHARS.choose(rng).unwrap()),
+                2 => {
+                    chars.drain(at..end);
+                }
+                3 => {
+                    let copy = chars[at..end].to_vec();
+                    chars.splice(end..end, copy);
+                }
+                _ => chars.truncate(at),
+            }
+        }
+
+        chars.into_iter().collect()
+    }
+
+    #[test]
+    fn test_mutated_inputs_dont_panic() {
+        for day in DAYS {
+            let dir = PathBuf::from(day.inputs_dir);
+
+            for name in available_names(&dir) {
+                let source = Source::Named {
+                    dir: dir.clone(),
+                    name,
+                };
+                let input = source.read().unwrap();
+                let mut rng = StdRng::seed_from_u64(day.number().into());
+
+                for _ in 0..20 {
+                    let mutant = mutate(&mut rng, &input);
+
+                    if catch_unwind(AssertUnwindSafe(|| day.run(&mutant, &Part::BOTH))).is_err() {
+                        panic!(
+                            "Day {} panicked on a mutant of {}:\n{}",
+                            day.number(),
+                            source,
+                            mutant
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
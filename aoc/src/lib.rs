@@ -0,0 +1,4 @@
+pub mod answers;
+pub mod days;
+pub mod report;
+pub mod verify;
@@ -0,0 +1,384 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use aoc::{
+    answers::{self, Answers},
+    days::{self, Day, DAYS},
+    report, verify,
+};
+use aoc_common::{
+    input::{self, Source, INPUTS_DIR_VAR},
+    Answer, Part, Solution,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use dive::{course::Course, Sub};
+use giant_squid::{
+    game::Game,
+    rules::{self, Mask, WinRule},
+    GiantSquid,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use sonar_sweep::profile::{Profile, ProfileOptions};
+
+#[derive(Parser)]
+#[clap(about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per line for each day, input and part, with answers and timings
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day when no day is given
+    Run {
+        day: Option<u8>,
+
+        /// Only solve this part
+        #[clap(short, long)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file, or from stdin when given `-`
+        #[clap(short, long, requires = "day")]
+        input: Option<Source>,
+
+        /// Directory of inputs laid out as `<day>/<name>.input`, defaults to the inputs bundled with each day
+        #[clap(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+
+        /// Which named input to solve when no `--input` is given
+        #[clap(short, long, default_value = "my", conflicts_with = "input")]
+        name: String,
+
+        #[clap(long, value_enum, default_value = "text")]
+        format: Format,
+    },
+    /// Solve every input of a day, or of every day, and check the answers against the registry
+    Verify {
+        day: Option<u8>,
+
+        /// The expected answers registry
+        #[clap(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+
+        /// Directory of inputs laid out as `<day>/<name>.input`, defaults to the inputs bundled with each day
+        #[clap(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Print a random input for a day
+    Generate {
+        day: u8,
+
+        /// How big an input to make, defaults to about the size of a real input
+        #[clap(short, long)]
+        size: Option<usize>,
+
+        /// Seed for the random input, a random seed is picked and reported when not given
+        #[clap(long)]
+        seed: Option<u64>,
+    },
+    /// Report runs, jumps, plateaus, trend reversals and anomalies in day 1's sonar readings
+    DepthProfile {
+        /// Read the sonar readings from this file, or from stdin when given `-`
+        #[clap(short, long)]
+        input: Option<Source>,
+
+        /// Directory of inputs laid out as `<day>/<name>.input`, defaults to the inputs bundled with each day
+        #[clap(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+
+        /// Which named input to report on when no `--input` is given
+        #[clap(short, long, default_value = "my", conflicts_with = "input")]
+        name: String,
+
+        /// How many readings either side of a reading to compare it with when looking for anomalies
+        #[clap(long, default_value_t = ProfileOptions::default().window)]
+        window: usize,
+
+        /// How many standard deviations from its neighbours make a reading an anomaly
+        #[clap(long, default_value_t = ProfileOptions::default().threshold)]
+        threshold: f64,
+
+        #[clap(long, value_enum, default_value = "text")]
+        format: Format,
+    },
+    /// Print the sub's position and aim after every move of a day 2 course as CSV
+    DiveTrajectory {
+        /// Read the course from this file, or from stdin when given `-`
+        #[clap(short, long)]
+        input: Option<Source>,
+
+        /// Directory of inputs laid out as `<day>/<name>.input`, defaults to the inputs bundled with each day
+        #[clap(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+
+        /// Which named input to follow when no `--input` is given
+        #[clap(short, long, default_value = "my", conflicts_with = "input")]
+        name: String,
+
+        /// How to read the moves
+        #[clap(short, long, value_enum, default_value = "aimed")]
+        model: DiveModel,
+    },
+    /// Play a day 4 bingo file by other rules, printing every card's win in order
+    Bingo {
+        /// Read the numbers and cards from this file, or from stdin when given `-`
+        #[clap(short, long)]
+        input: Option<Source>,
+
+        /// Directory of inputs laid out as `<day>/<name>.input`, defaults to the inputs bundled with each day
+        #[clap(long, env = INPUTS_DIR_VAR)]
+        inputs_dir: Option<PathBuf>,
+
+        /// Which named input to play when no `--input` is given
+        #[clap(short, long, default_value = "my", conflicts_with = "input")]
+        name: String,
+
+        /// The ways a card can win, separated by commas
+        #[clap(
+            short,
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_values = &["rows", "columns"]
+        )]
+        rules: Vec<BingoRule>,
+
+        /// A file of patterns drawn with `X` and `.`, any of which also wins a card of its size
+        #[clap(long)]
+        masks: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiveModel {
+    /// Part 1, up and down change the depth
+    Simple,
+    /// Part 2, up and down change the aim
+    Aimed,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BingoRule {
+    Rows,
+    Columns,
+    /// Either diagonal
+    Diagonals,
+    /// Both diagonals
+    X,
+    Corners,
+    Blackout,
+}
+
+impl BingoRule {
+    fn rule(self) -> Box<dyn WinRule> {
+        match self {
+            BingoRule::Rows => Box::new(rules::Rows),
+            BingoRule::Columns => Box::new(rules::Columns),
+            BingoRule::Diagonals => Box::new(rules::Diagonals),
+            BingoRule::X => Box::new(rules::X),
+            BingoRule::Corners => Box::new(rules::Corners),
+            BingoRule::Blackout => Box::new(rules::Blackout),
+        }
+    }
+}
+
+fn run_day(day: &Day, part: Option<Part>, source: &Source, format: Format) -> Result<()> {
+    let input = source.read()?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    let run = day.run(&input, &parts)?;
+
+    match format {
+        Format::Text => {
+            println!("Day {}: {} ({})", day.number(), day.name(), source);
+            for part in run.parts {
+                if let Answer::Lines(_) = part.answer {
+                    println!("Part {}:\n{}", part.part, part.answer);
+                } else {
+                    println!("Part {}: {}", part.part, part.answer);
+                }
+            }
+        }
+        Format::Json => {
+            for record in report::records(day, source, &run) {
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            inputs_dir,
+            name,
+            format,
+        } => {
+            let named = |day: &Day| Source::Named {
+                dir: input::day_dir(inputs_dir.as_deref(), day.number(), day.inputs_dir),
+                name: name.clone(),
+            };
+
+            match day {
+                Some(day) => {
+                    let day = days::get(day)?;
+                    run_day(day, part, &input.unwrap_or_else(|| named(day)), format)
+                }
+                None => DAYS.iter().try_for_each(|day| {
+                    run_day(day, part, &named(day), format)?;
+                    if format == Format::Text {
+                        println!();
+                    }
+
+                    Ok(())
+                }),
+            }
+        }
+        Command::Verify {
+            day,
+            answers,
+            inputs_dir,
+        } => {
+            let answers = Answers::load(&answers)?;
+            let days = match day {
+                Some(day) => vec![days::get(day)?],
+                None => DAYS.iter().collect(),
+            };
+
+            let checks: Vec<_> = days
+                .into_iter()
+                .flat_map(|day| verify::verify(day, &answers, inputs_dir.as_deref()))
+                .collect();
+            verify::print_table(&checks);
+
+            let failures = checks.iter().filter(|c| c.status.is_failure()).count();
+            if failures > 0 {
+                bail!("{} of {} checks failed", failures, checks.len());
+            }
+
+            Ok(())
+        }
+        Command::Generate { day, size, seed } => {
+            let day = days::get(day)?;
+            let seed = seed.unwrap_or_else(rand::random);
+            eprintln!("Generating day {} with seed {}", day.number(), seed);
+
+            print!("{}", day.generate(&mut StdRng::seed_from_u64(seed), size));
+
+            Ok(())
+        }
+        Command::DepthProfile {
+            input,
+            inputs_dir,
+            name,
+            window,
+            threshold,
+            format,
+        } => {
+            let source = input.unwrap_or_else(|| Source::Named {
+                dir: input::day_dir(inputs_dir.as_deref(), 1, sonar_sweep::INPUTS_DIR),
+                name,
+            });
+            let depths = sonar_sweep::transform_input(&source.read()?)?;
+            let profile = Profile::new(&depths, &ProfileOptions { window, threshold });
+
+            match format {
+                Format::Text => println!("{}", profile),
+                Format::Json => println!("{}", serde_json::to_string(&profile)?),
+            }
+
+            Ok(())
+        }
+        Command::DiveTrajectory {
+            input,
+            inputs_dir,
+            name,
+            model,
+        } => {
+            let source = input.unwrap_or_else(|| Source::Named {
+                dir: input::day_dir(inputs_dir.as_deref(), 2, dive::INPUTS_DIR),
+                name,
+            });
+            let course = Course::parse(&source.read()?)?;
+            let trajectory = match model {
+                DiveModel::Simple => Sub::record(&dive::model::Simple, &course.moves),
+                DiveModel::Aimed => Sub::record(&dive::model::Aimed, &course.moves),
+            }
+            .map_err(|e| course.locate(e))?;
+
+            for waypoint in &course.waypoints {
+                let sub = &trajectory.states()[waypoint.moves_before];
+                eprintln!(
+                    "Waypoint {} at {},{} aiming {}",
+                    waypoint.name, sub.pos.0, sub.pos.1, sub.aim
+                );
+            }
+            eprintln!(
+                "{} moves, {:.1} travelled, {} deepest",
+                course.moves.len(),
+                trajectory.distance(),
+                trajectory.max_depth()
+            );
+            trajectory.write_csv(std::io::stdout().lock())?;
+
+            Ok(())
+        }
+        Command::Bingo {
+            input,
+            inputs_dir,
+            name,
+            rules,
+            masks,
+        } => {
+            let source = input.unwrap_or_else(|| Source::Named {
+                dir: input::day_dir(inputs_dir.as_deref(), 4, giant_squid::INPUTS_DIR),
+                name,
+            });
+            let (numbers, boards) = GiantSquid::parse(&source.read()?)?;
+
+            let mut active: Vec<_> = rules.into_iter().map(BingoRule::rule).collect();
+            if let Some(path) = masks {
+                let text = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Couldn't read {}", path.display()))?;
+                let masks = Mask::parse(&text).with_context(|| format!("in {}", path.display()))?;
+
+                active.extend(
+                    masks
+                        .into_iter()
+                        .map(|mask| Box::new(mask) as Box<dyn WinRule>),
+                );
+            }
+
+            let wins = Game::new(active).wins_in_order(&numbers, &boards)?;
+            for win in &wins {
+                println!(
+                    "Card {} wins on call {} ({}) scoring {}",
+                    win.card + 1,
+                    win.calls,
+                    numbers[win.calls - 1],
+                    win.score
+                );
+            }
+            eprintln!("{} of {} cards won", wins.len(), boards.len());
+
+            Ok(())
+        }
+    }
+}
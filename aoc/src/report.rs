@@ -0,0 +1,65 @@
+use aoc_common::{input::Source, solution::Run, Answer, Part};
+use serde::Serialize;
+
+use crate::days::Day;
+
+/// One line of the runner's JSON output, describing a single part solved for a single input
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub name: &'static str,
+    pub input: String,
+    pub part: Part,
+    #[serde(flatten)]
+    pub answer: &'a Answer,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+}
+
+pub fn records<'a>(day: &Day, source: &Source, run: &'a Run) -> Vec<Record<'a>> {
+    run.parts
+        .iter()
+        .map(|part| Record {
+            day: day.number(),
+            name: day.name(),
+            input: source.name(),
+            part: part.part,
+            answer: &part.answer,
+            parse_time_ns: run.parse_time.as_nanos() as u64,
+            solve_time_ns: part.solve_time.as_nanos() as u64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_records_json() {
+        let day = days::get(13).unwrap();
+        let source = Source::Named {
+            dir: day.inputs_dir.into(),
+            name: "sample".to_owned(),
+        };
+        let run = day.run(&source.read().unwrap(), &Part::BOTH).unwrap();
+
+        let json: Vec<serde_json::Value> = records(day, &source, &run)
+            .iter()
+            .map(|r| serde_json::to_value(r).unwrap())
+            .collect();
+
+        assert_eq!(json[0]["day"], 13);
+        assert_eq!(json[0]["input"], "sample");
+        assert_eq!(json[0]["part"], 1);
+        assert_eq!(json[0]["answer_type"], "integer");
+        assert_eq!(json[0]["answer"], 17);
+        assert!(json[0]["parse_time_ns"].is_u64());
+        assert!(json[0]["solve_time_ns"].is_u64());
+
+        assert_eq!(json[1]["answer_type"], "lines");
+        assert_eq!(json[1]["answer"][0], "█████ ");
+        assert_eq!(json[1]["answer"].as_array().unwrap().len(), 5);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::BTreeSet, fmt, path::Path};
+
+use aoc_common::{
+    input::{self, Source},
+    Part,
+};
+
+use crate::{
+    answers::{self, Answers},
+    days::Day,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Solved, but there's no expected answer to compare against
+    Missing,
+    /// The input couldn't be read or the solver returned an error
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+            Status::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+impl Check {
+    fn details(&self) -> String {
+        let answer = self.answer.as_deref().map(one_line).unwrap_or_default();
+
+        match &self.status {
+            Status::Pass | Status::Missing => answer,
+            Status::Fail { expected } => format!("{} (expected {})", answer, one_line(expected)),
+            Status::Error(e) => e.clone(),
+        }
+    }
+}
+
+fn one_line(answer: &str) -> String {
+    let lines = answer.trim_matches('\n').lines().count();
+
+    if lines > 1 {
+        format!("({} lines)", lines)
+    } else {
+        answer.to_owned()
+    }
+}
+
+/// Every input worth checking for a day, the ones on disk plus any that have answers recorded
+fn input_names(day: &Day, dir: &Path, answers: &Answers) -> BTreeSet<String> {
+    input::available_names(dir)
+        .into_iter()
+        .chain(answers.inputs(day.number()).map(str::to_owned))
+        .collect()
+}
+
+/// Solves both parts of every input for `day` and compares them to the expected answers
+pub fn verify(day: &Day, answers: &Answers, inputs_dir: Option<&Path>) -> Vec<Check> {
+    let dir = input::day_dir(inputs_dir, day.number(), day.inputs_dir);
+    let mut checks = vec![];
+
+    for name in input_names(day, &dir, answers) {
+        let source = Source::Named {
+            dir: dir.clone(),
+            name: name.clone(),
+        };
+        let text = source.read();
+
+        for part in Part::BOTH {
+            let (answer, status) = match &text {
+                Err(e) => (None, Status::Error(format!("{:#}", e))),
+                Ok(text) => match day.solve(text, part) {
+                    Err(e) => (None, Status::Error(format!("{:#}", e))),
+                    Ok(answer) => {
+                        let status = match answers.get(day.number(), &name, part) {
+                            None => Status::Missing,
+                            Some(expected) if answers::matches(expected, &answer) => Status::Pass,
+                            Some(expected) => Status::Fail {
+                                expected: expected.to_owned(),
+                            },
+                        };
+
+                        (Some(answer), status)
+                    }
+                },
+            };
+
+            checks.push(Check {
+                day: day.number(),
+                input: name.clone(),
+                part,
+                answer,
+                status,
+            });
+        }
+    }
+
+    checks
+}
+
+pub fn print_table(checks: &[Check]) {
+    let input_width = checks
+        .iter()
+        .map(|c| c.input.len())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3}  {:<input_width$}  {:>4}  {:<7}  Answer",
+        "Day",
+        "Input",
+        "Part",
+        "Status",
+        input_width = input_width
+    );
+    for check in checks {
+        println!(
+            "{:>3}  {:<input_width$}  {:>4}  {:<7}  {}",
+            check.day,
+            check.input,
+            check.part,
+            check.status.to_string(),
+            check.details(),
+            input_width = input_width
+        );
+    }
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing, {} errors",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail { .. })),
+        count(|s| *s == Status::Missing),
+        count(|s| matches!(s, Status::Error(_))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_statuses() {
+        let answers = Answers::parse(
+            r#"
+[6.sample]
+part1 = "5934"
+part2 = "1"
+
+[6.nope]
+part1 = "1"
+"#,
+        )
+        .unwrap();
+        let day = days::get(6).unwrap();
+
+        let checks = verify(day, &answers, None);
+        let status = |input: &str, part: Part| {
+            &checks
+                .iter()
+                .find(|c| c.input == input && c.part == part)
+                .unwrap()
+                .status
+        };
+
+        assert_eq!(status("sample", Part::One), &Status::Pass);
+        assert_eq!(
+            status("sample", Part::Two),
+            &Status::Fail {
+                expected: "1".to_owned()
+            }
+        );
+        assert_eq!(status("my", Part::One), &Status::Missing);
+        assert!(matches!(status("nope", Part::One), Status::Error(_)));
+    }
+
+    #[test]
+    fn test_bundled_answers() {
+        let answers = Answers::load(Path::new(answers::DEFAULT_PATH)).unwrap();
+
+        for day in days::DAYS.iter() {
+            for check in verify(day, &answers, None) {
+                assert_eq!(
+                    check.status,
+                    Status::Pass,
+                    "Day {} {} part {}",
+                    check.day,
+                    check.input,
+                    check.part
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#!/bin/sh
+# Seeds each day's fuzzing corpus with the inputs bundled in the day's crate
+set -eu
+
+cd "$(dirname "$0")/.."
+
+for dir in [0-9]*-*/; do
+    day=$(printf "day%02d" "${dir%%-*}")
+    mkdir -p "fuzz/corpus/$day"
+
+    for input in "$dir"src/*.input; do
+        cp "$input" "fuzz/corpus/$day/$(basename "$input")"
+    done
+done
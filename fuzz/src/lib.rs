@@ -0,0 +1,13 @@
+use aoc_common::Part;
+
+/// Parses and solves both parts of `data` for `day`
+///
+/// Errors are fine, malformed input is meant to be reported, but any panic is a bug. Bytes that
+/// aren't UTF-8 never reach the solutions, since inputs are always read as strings
+pub fn run(day: u8, data: &[u8]) {
+    let day = aoc::days::get(day).expect("Fuzzing a day without a solution");
+
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day.run(input, &Part::BOTH);
+    }
+}
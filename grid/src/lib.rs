@@ -0,0 +1,375 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse::char_at, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    fn offset(&self, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+/// Which cells count as touching a given cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Up, down, left and right
+    Orthogonal,
+    /// Orthogonal plus the four diagonals
+    All,
+}
+
+impl Adjacency {
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+        const ALL: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+
+        match self {
+            Adjacency::Orthogonal => &ORTHOGONAL,
+            Adjacency::All => &ALL,
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which all need to be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(anyhow!(
+                "Row {} has {} cells but the first row has {}",
+                i + 1,
+                row.len(),
+                width
+            ));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.row * self.width + pos.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The in bounds neighbours of `pos`
+    ///
+    /// The iterator doesn't borrow the grid, so it can be used while mutating cells
+    pub fn neighbours(&self, pos: Pos, adjacency: Adjacency) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+
+        adjacency
+            .offsets()
+            .iter()
+            .filter_map(move |offset| pos.offset(*offset))
+            .filter(move |n| n.row < height && n.col < width)
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, Adjacency::Orthogonal)
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, Adjacency::All)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+        if col < self.width {
+            Some(self.cells.iter().skip(col).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.cells.iter().skip(col).step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid where every cell is a single digit, one row per line
+    pub fn parse_digits(s: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for line in s.trim().lines().map(str::trim) {
+            for (i, c) in line.char_indices() {
+                let digit = c.to_digit(10).ok_or_else(|| {
+                    ParseError::at(s, char_at(line, i), format!("`{}` isn't a digit", c))
+                })?;
+                cells.push(digit as u8);
+            }
+
+            // Every character is a digit by now, so bytes and cells line up
+            let row_width = *width.get_or_insert(line.len());
+            if line.len() != row_width {
+                return Err(ParseError::at(
+                    s,
+                    line,
+                    format!(
+                        "row has {} cells but the first row has {}",
+                        line.len(),
+                        row_width
+                    ),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        Grid::parse_digits("123\n456").unwrap()
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = sample();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(1, 0)], 4);
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse_digits("12\n3x").unwrap_err();
+        assert_eq!(err.message, "`x` isn't a digit");
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = Grid::parse_digits("12\n345").unwrap_err();
+        assert_eq!(err.message, "row has 3 cells but the first row has 2");
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = Grid::parse_digits("1█\n22").unwrap_err();
+        assert_eq!(err.message, "`█` isn't a digit");
+        assert_eq!((err.line, err.column), (1, 2));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let grid = sample();
+
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(Grid::parse_digits(&grid.to_string()).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            vec![Pos::new(0, 1), Pos::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Pos::new(2, 2)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn test_positions_and_mutation() {
+        let mut grid = sample();
+
+        assert_eq!(grid.positions().count(), 6);
+        assert_eq!(grid.positions().last(), Some(Pos::new(1, 2)));
+
+        grid[Pos::new(0, 2)] = 9;
+        for value in grid.values_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(grid.to_string(), "2310\n567");
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of a 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = sample()[Pos::new(5, 5)];
+    }
+}